@@ -0,0 +1,147 @@
+use core::fmt;
+use core::time::Duration;
+
+use crate::platform;
+
+/// The implementation [`utcnow()`](crate::utcnow) uses on the current target
+///
+/// Returned by [`BackendInfo::backend()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Backend {
+    /// `clock_gettime()` through the crate [`rustix`](https://docs.rs/rustix), e.g. on Linux and MacOS
+    Rustix,
+    /// `clock_gettime()` through the crate [`libc`](https://docs.rs/libc), e.g. on Android and NetBSD
+    Libc,
+    /// `clock_time_get()` through the crate [`wasi`](https://docs.rs/wasi)
+    Wasi,
+    /// `GetSystemTimePreciseAsFileTime()` through the crate [`winapi`](https://docs.rs/winapi)
+    Winapi,
+    /// `Date.now()` through the crate [`js-sys`](https://docs.rs/js-sys)
+    Web,
+    /// No native implementation exists for the target, see [`IMPLEMENTED`](crate::IMPLEMENTED)
+    Fallback,
+}
+
+impl Backend {
+    /// The name of the backend, e.g. `"rustix"`
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Backend::Rustix => "rustix",
+            Backend::Libc => "libc",
+            Backend::Wasi => "wasi",
+            Backend::Winapi => "winapi",
+            Backend::Web => "web",
+            Backend::Fallback => "fallback",
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Information about the implementation of [`utcnow()`](crate::utcnow) on the current target
+///
+/// Returned by [`backend_info()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BackendInfo {
+    backend: Backend,
+    clock_id: &'static str,
+    resolution: Option<Duration>,
+}
+
+impl BackendInfo {
+    /// The implementation that is used to query the time
+    #[must_use]
+    #[inline]
+    pub const fn backend(self) -> Backend {
+        self.backend
+    }
+
+    /// The clock or function that is queried, e.g. `"CLOCK_REALTIME"`
+    ///
+    /// | Backend               | Clock id                                       |
+    /// |-----------------------|------------------------------------------------|
+    /// | [`Backend::Rustix`]   | `"CLOCK_REALTIME"`                             |
+    /// | [`Backend::Libc`]     | `"CLOCK_REALTIME"`                             |
+    /// | [`Backend::Wasi`]     | `"CLOCKID_REALTIME"`                           |
+    /// | [`Backend::Winapi`]   | `"GetSystemTimePreciseAsFileTime"`             |
+    /// | [`Backend::Web`]      | `"Date.now"`                                   |
+    /// | [`Backend::Fallback`] | `"custom"`, `"SystemTime::now"`, or `"none"`   |
+    ///
+    /// For [`Backend::Fallback`] the clock id is `"custom"` with the feature `"custom"`,
+    /// `"SystemTime::now"` with the feature `"std"`, and `"none"` otherwise.
+    #[must_use]
+    #[inline]
+    pub const fn clock_id(self) -> &'static str {
+        self.clock_id
+    }
+
+    /// The resolution of the clock, if known
+    ///
+    /// On targets that use `clock_gettime()` or WASI, this is the value the operating system reports.
+    /// On Windows it is 100 ns, the unit of a `FILETIME`.
+    /// In web browsers it is 1 ms, the unit of `Date.now()`, but the browser may round the time even
+    /// more coarsely to mitigate timing attacks.
+    /// For [`Backend::Fallback`] the resolution is unknown.
+    #[must_use]
+    #[inline]
+    pub const fn resolution(self) -> Option<Duration> {
+        self.resolution
+    }
+}
+
+/// Get information about the implementation of [`utcnow()`](crate::utcnow) on the current target
+///
+/// Overrides installed with the feature `"mock"` are not reflected in the result.
+///
+/// # Example
+///
+/// ```
+/// # use core::time::Duration;
+/// let info = utcnow::backend_info();
+/// println!("utcnow() uses {}: {}", info.backend(), info.clock_id());
+///
+/// let sub_millisecond = match info.resolution() {
+///     Some(resolution) => resolution < Duration::from_millis(1),
+///     None => false,
+/// };
+/// ```
+#[must_use]
+pub fn backend_info() -> BackendInfo {
+    BackendInfo {
+        backend: platform::BACKEND,
+        clock_id: platform::CLOCK_ID,
+        resolution: platform::resolution(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use crate::backend_info;
+
+    #[test]
+    fn test_backend_info() {
+        let info = backend_info();
+        assert_eq!(info.backend().to_string(), info.backend().name());
+        assert!(!info.clock_id().is_empty());
+        if let Some(resolution) = info.resolution() {
+            assert!(resolution.as_nanos() > 0);
+            assert!(resolution <= Duration::from_millis(1));
+        }
+
+        #[cfg(all(target_os = "linux", not(utcnow_force_custom)))]
+        {
+            assert_eq!(info.backend(), crate::Backend::Rustix);
+            assert_eq!(info.clock_id(), "CLOCK_REALTIME");
+            assert!(info.resolution().is_some());
+        }
+    }
+}
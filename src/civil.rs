@@ -0,0 +1,501 @@
+use crate::UtcTime;
+
+const SECS_PER_DAY: i64 = 86_400;
+
+/// Days between 0000-03-01 and 1970-01-01 in the proleptic Gregorian calendar
+const DAYS_0000_03_01_TO_EPOCH: i64 = 719_468;
+
+/// Days in a 400 year cycle of the Gregorian calendar
+const DAYS_PER_ERA: i64 = 146_097;
+
+/// A date and time in the [proleptic Gregorian calendar](https://en.wikipedia.org/w/index.php?title=Proleptic_Gregorian_calendar&oldid=1160617428), in UTC
+///
+/// Use [`UtcTime::to_civil()`] and [`UtcTime::from_civil()`] to convert between the representations.
+/// The year `0` is the year 1 BC, the year `-1` is the year 2 BC, etc.
+///
+/// Leap seconds are not representable, because [`UtcTime`] is a Unix time.
+///
+/// # Example
+///
+/// ```
+/// # use utcnow::{CivilDateTime, UtcTime, Weekday};
+/// let timestamp = UtcTime::new(1_659_545_693, 895_531_827).unwrap();
+/// let civil = timestamp.to_civil();
+/// assert_eq!(
+///     civil,
+///     CivilDateTime::new(2022, 8, 3, 16, 54, 53, 895_531_827).unwrap(),
+/// );
+/// assert_eq!(civil.weekday(), Weekday::Wednesday);
+/// assert_eq!(UtcTime::from_civil(civil), Some(timestamp));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CivilDateTime {
+    /// Year, `0` being 1 BC
+    pub year: i64,
+    /// Month of the year, `1..=12`
+    pub month: u8,
+    /// Day of the month, `1..=31`
+    pub day: u8,
+    /// Hour of the day, `0..=23`
+    pub hour: u8,
+    /// Minute of the hour, `0..=59`
+    pub minute: u8,
+    /// Second of the minute, `0..=59`
+    pub second: u8,
+    /// Nanosecond of the second, `0..=999_999_999`
+    pub nanosecond: u32,
+}
+
+/// A day of the week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    /// Monday, the first day of the week according to ISO 8601
+    Monday,
+    /// Tuesday
+    Tuesday,
+    /// Wednesday
+    Wednesday,
+    /// Thursday
+    Thursday,
+    /// Friday
+    Friday,
+    /// Saturday
+    Saturday,
+    /// Sunday
+    Sunday,
+}
+
+impl Weekday {
+    /// Day of the week, counting from Monday = `1` to Sunday = `7`, as used by ISO 8601
+    #[must_use]
+    #[inline]
+    pub const fn number_from_monday(self) -> u8 {
+        self as u8 + 1
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.56")]
+    const fn from_days(days: i64) -> Self {
+        // 1970-01-01 was a Thursday
+        match (days + 3).rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+}
+
+impl CivilDateTime {
+    /// Build a new [`CivilDateTime`], returning [`None`] if any of the fields is out of range
+    ///
+    /// The year is not checked, so the value might not be representable as a [`UtcTime`].
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn new(
+        year: i64,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Option<Self> {
+        let value = Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        };
+        if value.is_valid() { Some(value) } else { None }
+    }
+
+    /// Midnight at the start of the given date, returning [`None`] if the date is invalid
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn from_date(year: i64, month: u8, day: u8) -> Option<Self> {
+        Self::new(year, month, day, 0, 0, 0, 0)
+    }
+
+    const fn from_date_unchecked(year: i64, month: u8, day: u8) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+        }
+    }
+
+    /// `true` if all fields are in range
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn is_valid(&self) -> bool {
+        self.month >= 1
+            && self.month <= 12
+            && self.day >= 1
+            && self.day <= days_in_month(self.year, self.month)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second < 60
+            && self.nanosecond < 1_000_000_000
+    }
+
+    /// Day of the week
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{CivilDateTime, Weekday};
+    /// let date = CivilDateTime::from_date(1970, 1, 1).unwrap();
+    /// assert_eq!(date.weekday(), Weekday::Thursday);
+    /// ```
+    #[must_use]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.56")]
+    pub fn weekday(&self) -> Weekday {
+        // The weekdays repeat every 400 years, so we can reduce the year first to avoid overflows.
+        let year = self.year.rem_euclid(400) + 400;
+        Weekday::from_days(days_from_civil(year, self.month, self.day))
+    }
+
+    /// Day of the year, `1..=366`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::CivilDateTime;
+    /// let date = CivilDateTime::from_date(2024, 12, 31).unwrap();
+    /// assert_eq!(date.ordinal(), 366);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.56")]
+    pub fn ordinal(&self) -> u16 {
+        let year = self.year.rem_euclid(400);
+        (days_from_civil(year, self.month, self.day) - days_from_civil(year, 1, 1) + 1) as u16
+    }
+
+    /// ISO 8601 week date, returned as `(week-numbering year, week of the year)`
+    ///
+    /// The week-numbering year can differ from [`year`](CivilDateTime::year)
+    /// for the first and last few days of the year.
+    /// If it is out of range of an [`i64`], i.e. for the first days of the year [`i64::MIN`]
+    /// or the last days of the year [`i64::MAX`], then the week-numbering year saturates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::CivilDateTime;
+    /// let date = CivilDateTime::from_date(2021, 1, 3).unwrap();
+    /// assert_eq!(date.iso_week(), (2020, 53));
+    /// let date = CivilDateTime::from_date(2024, 12, 30).unwrap();
+    /// assert_eq!(date.iso_week(), (2025, 1));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.56")]
+    pub fn iso_week(&self) -> (i64, u8) {
+        let ordinal = self.ordinal() as i64;
+        let weekday = self.weekday().number_from_monday() as i64;
+        let week = (ordinal - weekday + 10) / 7;
+        if week < 1 {
+            // The calendar repeats every 400 years, so this cannot overflow.
+            let weeks = weeks_in_year(self.year.rem_euclid(400) - 1);
+            (self.year.saturating_sub(1), weeks)
+        } else if week > weeks_in_year(self.year) as i64 {
+            (self.year.saturating_add(1), 1)
+        } else {
+            (self.year, week as u8)
+        }
+    }
+}
+
+impl UtcTime {
+    /// Split the timestamp into its date and time in the proleptic Gregorian calendar
+    ///
+    /// This conversion cannot fail. All [`UtcTime`]s are representable as [`CivilDateTime`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let civil = UtcTime::new(-1, 0).unwrap().to_civil();
+    /// assert_eq!((civil.year, civil.month, civil.day), (1969, 12, 31));
+    /// assert_eq!((civil.hour, civil.minute, civil.second), (23, 59, 59));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.56")]
+    pub fn to_civil(self) -> CivilDateTime {
+        let days = self.secs.div_euclid(SECS_PER_DAY);
+        let secs_of_day = self.secs.rem_euclid(SECS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        CivilDateTime {
+            year,
+            month,
+            day,
+            hour: (secs_of_day / 3600) as u8,
+            minute: (secs_of_day / 60 % 60) as u8,
+            second: (secs_of_day % 60) as u8,
+            nanosecond: self.nanos.get(),
+        }
+    }
+
+    /// Convert a date and time in the proleptic Gregorian calendar to a timestamp
+    ///
+    /// Returns [`None`] if `civil` is not [valid](CivilDateTime::is_valid),
+    /// or if the timestamp is out of range for a [`UtcTime`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{CivilDateTime, UtcTime};
+    /// let civil = CivilDateTime::new(2022, 8, 3, 16, 54, 53, 895_531_827).unwrap();
+    /// let timestamp = UtcTime::from_civil(civil).unwrap();
+    /// assert_eq!(timestamp, UtcTime::new(1_659_545_693, 895_531_827).unwrap());
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_lossless)]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.56")]
+    pub fn from_civil(civil: CivilDateTime) -> Option<Self> {
+        if !civil.is_valid() {
+            return None;
+        }
+
+        // Only the year can be out of range. Reduce it to 400 year eras to prevent overflows.
+        let era = civil.year.div_euclid(400);
+        let days = days_from_civil(civil.year.rem_euclid(400), civil.month, civil.day) as i128
+            + era as i128 * DAYS_PER_ERA as i128;
+        let secs = days * SECS_PER_DAY as i128
+            + civil.hour as i128 * 3600
+            + civil.minute as i128 * 60
+            + civil.second as i128;
+        if secs < i64::MIN as i128 || secs > i64::MAX as i128 {
+            return None;
+        }
+        Some(unsafe { Self::new_unchecked(secs as i64, civil.nanosecond) })
+    }
+}
+
+/// `true` if `year` has a February 29th
+#[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+#[const_fn::const_fn("1.56")]
+const fn is_leap_year(year: i64) -> bool {
+    year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0)
+}
+
+#[const_fn::const_fn("1.56")]
+const fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Number of ISO 8601 weeks in a week-numbering year, `52` or `53`
+#[const_fn::const_fn("1.56")]
+const fn weeks_in_year(year: i64) -> u8 {
+    // A year has 53 weeks if it starts on a Thursday, or if it is a leap year starting on a Wednesday.
+    match CivilDateTime::from_date_unchecked(year, 1, 1).weekday() {
+        Weekday::Thursday => 53,
+        Weekday::Wednesday if is_leap_year(year) => 53,
+        _ => 52,
+    }
+}
+
+// The following two algorithms were taken from <https://howardhinnant.github.io/date_algorithms.html>.
+// The year is shifted to start on March 1st, so that the leap day is the last day of the year.
+
+/// Convert days since 1970-01-01 into `(year, month, day)`
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+#[const_fn::const_fn("1.56")]
+const fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + DAYS_0000_03_01_TO_EPOCH;
+    let era = days.div_euclid(DAYS_PER_ERA);
+    let day_of_era = days.rem_euclid(DAYS_PER_ERA); // [0, 146096]
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365; // [0, 399]
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100); // [0, 365]
+    let shifted_month = (5 * day_of_year + 2) / 153; // [0, 11]
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1; // [1, 31]
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    }; // [1, 12]
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month as u8, day as u8)
+}
+
+/// Convert a date into days since 1970-01-01
+///
+/// The caller has to ensure that the result does not overflow,
+/// i.e. `year` must not be more than some 25 quadrillion years away from 1970.
+#[allow(clippy::cast_lossless)]
+#[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+#[const_fn::const_fn("1.56")]
+const fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400); // [0, 399]
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 } as i64; // [0, 11]
+    let day_of_year = (153 * shifted_month + 2) / 5 + day as i64 - 1; // [0, 365]
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year; // [0, 146096]
+    era * DAYS_PER_ERA + day_of_era - DAYS_0000_03_01_TO_EPOCH
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CivilDateTime, SECS_PER_DAY, Weekday, days_in_month, weeks_in_year};
+    use crate::UtcTime;
+
+    #[test]
+    fn test_known_dates() {
+        let civil = UtcTime::EPOCH.to_civil();
+        assert_eq!(civil, CivilDateTime::from_date(1970, 1, 1).unwrap());
+        assert_eq!(civil.weekday(), Weekday::Thursday);
+        assert_eq!(civil.ordinal(), 1);
+        assert_eq!(civil.iso_week(), (1970, 1));
+
+        let civil = UtcTime::new(951_782_400, 0).unwrap().to_civil();
+        assert_eq!(civil, CivilDateTime::from_date(2000, 2, 29).unwrap());
+        assert_eq!(civil.weekday(), Weekday::Tuesday);
+        assert_eq!(civil.ordinal(), 60);
+
+        let civil = UtcTime::new(-62_135_596_800, 0).unwrap().to_civil();
+        assert_eq!(civil, CivilDateTime::from_date(1, 1, 1).unwrap());
+        assert_eq!(civil.weekday(), Weekday::Monday);
+
+        let civil = UtcTime::new(-62_167_219_200 - 1, 0).unwrap().to_civil();
+        assert_eq!(
+            civil,
+            CivilDateTime::new(-1, 12, 31, 23, 59, 59, 0).unwrap(),
+        );
+
+        assert_eq!(CivilDateTime::from_date(1900, 2, 29), None);
+        assert_eq!(CivilDateTime::from_date(2023, 4, 31), None);
+        assert_eq!(CivilDateTime::from_date(2023, 13, 1), None);
+        assert_eq!(CivilDateTime::new(2023, 1, 1, 24, 0, 0, 0), None);
+        assert_eq!(CivilDateTime::new(2023, 1, 1, 0, 0, 60, 0), None);
+        assert_eq!(CivilDateTime::new(2023, 1, 1, 0, 0, 0, 1_000_000_000), None);
+    }
+
+    #[test]
+    fn test_extremes() {
+        for &value in &[UtcTime::MIN, UtcTime::MAX] {
+            let civil = value.to_civil();
+            assert!(civil.is_valid());
+            assert_eq!(UtcTime::from_civil(civil), Some(value));
+            let _ = civil.iso_week();
+        }
+        assert_eq!(
+            UtcTime::MAX.to_civil(),
+            CivilDateTime::new(292_277_026_596, 12, 4, 15, 30, 7, 999_999_999).unwrap(),
+        );
+        assert_eq!(
+            UtcTime::MIN.to_civil(),
+            CivilDateTime::new(-292_277_022_657, 1, 27, 8, 29, 52, 0).unwrap(),
+        );
+
+        let mut civil = UtcTime::MAX.to_civil();
+        civil.second += 1;
+        assert!(civil.is_valid());
+        assert_eq!(UtcTime::from_civil(civil), None);
+
+        let civil = CivilDateTime::from_date(i64::MAX, 12, 31).unwrap();
+        assert_eq!(UtcTime::from_civil(civil), None);
+        let _ = civil.weekday();
+        let _ = civil.ordinal();
+        assert_eq!(civil.iso_week(), (i64::MAX, 53));
+        let civil = CivilDateTime::from_date(i64::MIN, 1, 1).unwrap();
+        assert_eq!(UtcTime::from_civil(civil), None);
+        let _ = civil.weekday();
+        let _ = civil.ordinal();
+        assert_eq!(civil.iso_week(), (i64::MIN, 52));
+    }
+
+    /// Walk day by day through a naïvely implemented calendar and compare the results
+    #[test]
+    fn test_round_trip_days() {
+        let (mut year, mut month, mut day) = (-2001, 1, 1);
+        let mut days = UtcTime::from_civil(CivilDateTime::from_date(year, month, day).unwrap())
+            .unwrap()
+            .as_secs()
+            / SECS_PER_DAY;
+        let mut weekday = UtcTime::new(days * SECS_PER_DAY, 0)
+            .unwrap()
+            .to_civil()
+            .weekday()
+            .number_from_monday();
+        let mut ordinal = 1;
+        let mut iso_week: Option<(i64, u8)> = None;
+
+        while year <= 2401 {
+            let secs = days * SECS_PER_DAY + 12_345;
+            let timestamp = UtcTime::new(secs, 678).unwrap();
+            let civil = timestamp.to_civil();
+            assert_eq!(
+                civil,
+                CivilDateTime::new(year, month, day, 3, 25, 45, 678).unwrap(),
+            );
+            assert_eq!(UtcTime::from_civil(civil).unwrap().as_secs(), secs);
+            assert_eq!(civil.weekday().number_from_monday(), weekday);
+            assert_eq!(civil.ordinal(), ordinal);
+
+            let (iso_year, week) = civil.iso_week();
+            assert!((1..=weeks_in_year(iso_year)).contains(&week));
+            assert!((year - 1..=year + 1).contains(&iso_year));
+            match iso_week {
+                Some(prev) if weekday == 1 => {
+                    let next = if prev.1 == weeks_in_year(prev.0) {
+                        (prev.0 + 1, 1)
+                    } else {
+                        (prev.0, prev.1 + 1)
+                    };
+                    assert_eq!((iso_year, week), next);
+                },
+                Some(prev) => assert_eq!((iso_year, week), prev),
+                None => {},
+            }
+            iso_week = Some((iso_year, week));
+
+            days += 1;
+            weekday = weekday % 7 + 1;
+            ordinal += 1;
+            day += 1;
+            if day > days_in_month(year, month) {
+                day = 1;
+                month += 1;
+                if month > 12 {
+                    month = 1;
+                    year += 1;
+                    ordinal = 1;
+                }
+            }
+        }
+    }
+}
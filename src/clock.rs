@@ -0,0 +1,357 @@
+#[cfg(feature = "std")]
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::{Result, UtcDelta, UtcTime, platform};
+
+/// A source of [`UtcTime`]s
+///
+/// Write code that needs the current time against `impl Clock` instead of calling [`utcnow()`](crate::utcnow)
+/// directly. Then you can pass a [`SystemClock`] in production, and e.g. a [`FixedClock`] or [`ManualClock`] in tests.
+///
+/// # Example
+///
+/// ```
+/// # use utcnow::{Clock, FixedClock, UtcTime};
+/// fn is_weekend(clock: &impl Clock) -> bool {
+///     clock.now().unwrap().to_civil().weekday().number_from_monday() >= 6
+/// }
+///
+/// // Saturday, 2022-08-06
+/// let clock = FixedClock(UtcTime::new(1_659_800_000, 0).unwrap());
+/// assert!(is_weekend(&clock));
+/// ```
+pub trait Clock {
+    /// Get the current time according to this clock
+    ///
+    /// # Errors
+    ///
+    /// See [`utcnow()`](crate::utcnow) for further information.
+    fn now(&self) -> Result<UtcTime>;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    #[inline]
+    fn now(&self) -> Result<UtcTime> {
+        C::now(self)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &mut C {
+    #[inline]
+    fn now(&self) -> Result<UtcTime> {
+        C::now(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Clock + ?Sized> Clock for std::boxed::Box<C> {
+    #[inline]
+    fn now(&self) -> Result<UtcTime> {
+        C::now(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Clock + ?Sized> Clock for std::rc::Rc<C> {
+    #[inline]
+    fn now(&self) -> Result<UtcTime> {
+        C::now(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Clock + ?Sized> Clock for std::sync::Arc<C> {
+    #[inline]
+    fn now(&self) -> Result<UtcTime> {
+        C::now(self)
+    }
+}
+
+/// The clock of the system, i.e. the same as calling [`utcnow()`](crate::utcnow)
+///
+/// Overrides installed with the feature `"mock"` are ignored by this clock.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> Result<UtcTime> {
+        platform::utcnow()
+    }
+}
+
+/// A clock that is stopped at the contained time
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedClock(pub UtcTime);
+
+impl Clock for FixedClock {
+    #[inline]
+    fn now(&self) -> Result<UtcTime> {
+        Ok(self.0)
+    }
+}
+
+/// A clock that is ahead (or behind, if the offset is negative) of another clock
+///
+/// If the result would overflow, it is clamped to [`UtcTime::MIN`]..=[`UtcTime::MAX`].
+///
+/// # Example
+///
+/// ```
+/// # use utcnow::{Clock, FixedClock, OffsetClock, UtcDelta, UtcTime};
+/// let clock = OffsetClock::with_clock(FixedClock(UtcTime::EPOCH), UtcDelta::new(-60, 0).unwrap());
+/// assert_eq!(clock.now().unwrap(), UtcTime::new(-60, 0).unwrap());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OffsetClock<C = SystemClock> {
+    clock: C,
+    offset: UtcDelta,
+}
+
+impl OffsetClock {
+    /// A clock that is `offset` ahead of the [`SystemClock`]
+    #[must_use]
+    #[inline]
+    pub const fn new(offset: UtcDelta) -> Self {
+        Self::with_clock(SystemClock, offset)
+    }
+}
+
+impl<C> OffsetClock<C> {
+    /// A clock that is `offset` ahead of another `clock`
+    #[must_use]
+    #[inline]
+    pub const fn with_clock(clock: C, offset: UtcDelta) -> Self {
+        Self { clock, offset }
+    }
+
+    /// The offset that is added to the inner clock
+    #[must_use]
+    #[inline]
+    pub const fn offset(&self) -> UtcDelta {
+        self.offset
+    }
+
+    /// The inner clock
+    #[must_use]
+    #[inline]
+    pub const fn inner(&self) -> &C {
+        &self.clock
+    }
+}
+
+impl<C: Clock> Clock for OffsetClock<C> {
+    #[inline]
+    fn now(&self) -> Result<UtcTime> {
+        Ok(self.clock.now()?.saturating_add(self.offset))
+    }
+}
+
+/// A clock that only changes if you tell it to
+///
+/// The clock can be shared between threads and modified through a shared reference.
+/// This type is only available with the feature `"std"`.
+///
+/// # Example
+///
+/// ```
+/// # use core::time::Duration;
+/// # use utcnow::{Clock, ManualClock, UtcTime};
+/// let clock = ManualClock::new(UtcTime::EPOCH);
+/// assert_eq!(clock.now().unwrap(), UtcTime::EPOCH);
+///
+/// clock.advance(Duration::from_secs(5));
+/// assert_eq!(clock.now().unwrap().as_secs(), 5);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    time: Mutex<UtcTime>,
+}
+
+#[cfg(feature = "std")]
+impl ManualClock {
+    /// A clock that is stopped at `time` until you modify it
+    #[must_use]
+    #[inline]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.63")]
+    pub fn new(time: UtcTime) -> Self {
+        Self {
+            time: Mutex::new(time),
+        }
+    }
+
+    /// The current time of the clock, same as [`Clock::now()`] but infallible
+    #[must_use]
+    pub fn get(&self) -> UtcTime {
+        *lock(&self.time)
+    }
+
+    /// Set the clock to `time`
+    pub fn set(&self, time: UtcTime) {
+        *lock(&self.time) = time;
+    }
+
+    /// Move the clock forward, clamped at [`UtcTime::MAX`]
+    pub fn advance(&self, duration: Duration) {
+        let mut time = lock(&self.time);
+        *time = time.saturating_add_duration(duration);
+    }
+
+    /// Move the clock backward, clamped at [`UtcTime::MIN`]
+    pub fn rewind(&self, duration: Duration) {
+        let mut time = lock(&self.time);
+        *time = time.saturating_sub_duration(duration);
+    }
+
+    /// Move the clock forward or backward, clamped to [`UtcTime::MIN`]..=[`UtcTime::MAX`]
+    pub fn shift(&self, delta: UtcDelta) {
+        let mut time = lock(&self.time);
+        *time = time.saturating_add(delta);
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for ManualClock {
+    #[inline]
+    fn now(&self) -> Result<UtcTime> {
+        Ok(self.get())
+    }
+}
+
+/// A clock that advances by a fixed step every time it is queried
+///
+/// The first call to [`Clock::now()`] returns the start time.
+/// The clock stops at [`UtcTime::MAX`].
+/// This type is only available with the feature `"std"`.
+///
+/// # Example
+///
+/// ```
+/// # use core::time::Duration;
+/// # use utcnow::{Clock, SteppingClock, UtcTime};
+/// let clock = SteppingClock::new(UtcTime::EPOCH, Duration::from_millis(500));
+/// assert_eq!(clock.now().unwrap().as_millis(), 0);
+/// assert_eq!(clock.now().unwrap().as_millis(), 500);
+/// assert_eq!(clock.now().unwrap().as_millis(), 1000);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct SteppingClock {
+    next: Mutex<UtcTime>,
+    step: Duration,
+}
+
+#[cfg(feature = "std")]
+impl SteppingClock {
+    /// A clock that starts at `start`, and advances by `step` with every call to [`Clock::now()`]
+    #[must_use]
+    #[inline]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.63")]
+    pub fn new(start: UtcTime, step: Duration) -> Self {
+        Self {
+            next: Mutex::new(start),
+            step,
+        }
+    }
+
+    /// The time that the next call to [`Clock::now()`] will return
+    #[must_use]
+    pub fn peek(&self) -> UtcTime {
+        *lock(&self.next)
+    }
+
+    /// The amount of time the clock advances with every call
+    #[must_use]
+    #[inline]
+    pub const fn step(&self) -> Duration {
+        self.step
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for SteppingClock {
+    fn now(&self) -> Result<UtcTime> {
+        let mut next = lock(&self.next);
+        let now = *next;
+        *next = now.saturating_add_duration(self.step);
+        Ok(now)
+    }
+}
+
+/// A [`UtcTime`] cannot be left in an inconsistent state, so we can ignore poisoning.
+#[cfg(feature = "std")]
+fn lock(mutex: &Mutex<UtcTime>) -> MutexGuard<'_, UtcTime> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Clock, FixedClock, OffsetClock, SystemClock, UtcDelta, UtcTime};
+
+    #[test]
+    fn test_system_clock() {
+        let before = SystemClock.now().unwrap();
+        let now = SystemClock.now().unwrap();
+        assert!(before <= now);
+
+        let clock = OffsetClock::new(UtcDelta::new(-3600, 0).unwrap());
+        assert!(clock.now().unwrap() < now);
+    }
+
+    #[test]
+    fn test_offset_clock() {
+        let clock = OffsetClock::with_clock(FixedClock(UtcTime::MAX), UtcDelta::new(1, 0).unwrap());
+        assert_eq!(clock.now().unwrap(), UtcTime::MAX);
+
+        let clock = OffsetClock::with_clock(&clock, UtcDelta::new(-2, 0).unwrap());
+        assert_eq!(
+            clock.now().unwrap(),
+            UtcTime::new(i64::MAX - 2, 999_999_999).unwrap(),
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_manual_clock() {
+        use core::time::Duration;
+        use std::sync::Arc;
+
+        use crate::ManualClock;
+
+        let clock = Arc::new(ManualClock::new(UtcTime::EPOCH));
+        let clone = Arc::clone(&clock);
+        std::thread::spawn(move || clone.advance(Duration::from_secs(10)))
+            .join()
+            .unwrap();
+        assert_eq!(clock.now().unwrap().as_secs(), 10);
+
+        clock.rewind(Duration::from_secs(15));
+        assert_eq!(clock.now().unwrap().as_secs(), -5);
+        clock.shift(UtcDelta::new(-1, 500_000_000).unwrap());
+        assert_eq!(clock.now().unwrap().as_millis(), -5_500);
+        clock.set(UtcTime::MAX);
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(clock.get(), UtcTime::MAX);
+
+        let clock: Box<dyn Clock + Send + Sync> = Box::new(ManualClock::default());
+        assert_eq!(clock.now().unwrap(), UtcTime::EPOCH);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_stepping_clock() {
+        use core::time::Duration;
+
+        use crate::SteppingClock;
+
+        let clock = SteppingClock::new(UtcTime::new(-1, 0).unwrap(), Duration::from_millis(300));
+        let times: Vec<_> = (0..5).map(|_| clock.now().unwrap().as_millis()).collect();
+        assert_eq!(times, [-1000, -700, -400, -100, 200]);
+        assert_eq!(clock.peek().as_millis(), 500);
+    }
+}
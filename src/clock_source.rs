@@ -0,0 +1,317 @@
+use core::fmt;
+use core::time::Duration;
+
+use crate::u30::U30;
+use crate::{ConversionError, ErrorKind, UtcTime, platform};
+
+/// A clock of the operating system that can be read with [`now_from()`]
+///
+/// Not every clock is available on every target platform.
+/// Use [`ClockSource::is_implemented()`] to find out if a clock can be read on the current target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ClockSource {
+    /// The system-wide wall clock, i.e. the same clock [`utcnow()`](crate::utcnow) uses, e.g. `CLOCK_REALTIME`
+    Realtime,
+    /// A faster, but less precise version of [`ClockSource::Realtime`], e.g. `CLOCK_REALTIME_COARSE`
+    RealtimeCoarse,
+    /// International Atomic Time, i.e. a wall clock without leap seconds, e.g. `CLOCK_TAI`
+    ///
+    /// Please notice that the offset to [`ClockSource::Realtime`] is only correct
+    /// if the operating system was told the current TAI offset, e.g. by an NTP or PTP daemon.
+    Tai,
+    /// A clock that cannot be set, e.g. `CLOCK_MONOTONIC`
+    ///
+    /// The starting point of the clock is unspecified, and changes with every reboot.
+    /// Whether the time the system was suspended is counted depends on the platform:
+    /// e.g. Linux does not count it, but macOS and Windows (`QueryPerformanceCounter()`) do.
+    /// Use [`ClockSource::Boottime`] if you need it to be counted.
+    Monotonic,
+    /// A clock that cannot be set, and always includes the time the system was suspended, e.g. `CLOCK_BOOTTIME`
+    Boottime,
+}
+
+impl ClockSource {
+    /// `true` if reading this clock is implemented for the target platform
+    ///
+    /// Mirrors [`IMPLEMENTED`](crate::IMPLEMENTED) for [`ClockSource::Realtime`].
+    /// If `false`, [`now_from()`] will always fail with [`ErrorKind::Unsupported`].
+    #[must_use]
+    #[inline]
+    pub const fn is_implemented(self) -> bool {
+        platform::clock_implemented(self)
+    }
+
+    /// `true` if [`now_from()`] cannot fail for this clock
+    ///
+    /// Mirrors [`INFALLIBLE`](crate::INFALLIBLE) for [`ClockSource::Realtime`].
+    #[must_use]
+    #[inline]
+    pub const fn is_infallible(self) -> bool {
+        platform::clock_infallible(self)
+    }
+
+    /// `true` if the readings of this clock are seconds since 1970-01-01 in UTC
+    #[must_use]
+    #[inline]
+    pub const fn is_utc(self) -> bool {
+        matches!(self, ClockSource::Realtime | ClockSource::RealtimeCoarse)
+    }
+}
+
+/// A reading of a [`ClockSource`]
+///
+/// Like [`UtcTime`], the reading is stored as whole seconds and nanoseconds,
+/// but the meaning of the value depends on the [`source()`](RawClockReading::source).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawClockReading {
+    source: ClockSource,
+    secs: i64,
+    nanos: U30,
+}
+
+impl RawClockReading {
+    /// Create a new reading
+    ///
+    /// # Safety
+    ///
+    /// `nanos` must be in the range `0..1_000_000_000`.
+    #[must_use]
+    #[inline]
+    #[const_fn::const_fn("1.56")]
+    pub(crate) unsafe fn new_unchecked(source: ClockSource, secs: i64, nanos: u32) -> Self {
+        Self {
+            source,
+            secs,
+            nanos: U30::new_unchecked(nanos),
+        }
+    }
+
+    /// The clock this reading was taken from
+    #[must_use]
+    #[inline]
+    pub const fn source(self) -> ClockSource {
+        self.source
+    }
+
+    /// Total number of whole seconds of the reading
+    #[must_use]
+    #[inline]
+    pub const fn as_secs(self) -> i64 {
+        self.secs
+    }
+
+    /// Fractional number of nanoseconds of the reading
+    #[must_use]
+    #[inline]
+    #[const_fn::const_fn("1.56")]
+    pub fn subsec_nanos(self) -> u32 {
+        self.nanos.get()
+    }
+
+    /// Total number of whole nanoseconds of the reading
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn as_nanos(self) -> i128 {
+        (self.secs as i128 * 1_000_000_000) + (self.nanos.get() as i128)
+    }
+
+    /// Convert the reading to a [`Duration`]
+    ///
+    /// # Errors
+    ///
+    /// Fails if the reading is negative.
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.58")]
+    pub fn into_duration(self) -> Result<Duration, ConversionError> {
+        let secs = match self.secs {
+            secs @ 0..=i64::MAX => secs as u64,
+            _ => return Err(ConversionError::NEGATIVE),
+        };
+        Ok(Duration::new(secs, self.nanos.get()))
+    }
+
+    /// Interpret the reading as [`UtcTime`]
+    ///
+    /// Returns `None` unless the reading was taken from a clock [in UTC](ClockSource::is_utc).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{ClockSource, now_from};
+    /// let reading = now_from(ClockSource::Realtime).unwrap();
+    /// assert!(reading.to_utc_time().unwrap().as_secs() > 1_658_711_810);
+    /// ```
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn to_utc_time(self) -> Option<UtcTime> {
+        if self.source.is_utc() {
+            Some(unsafe { UtcTime::new_unchecked(self.secs, self.nanos.get()) })
+        } else {
+            None
+        }
+    }
+}
+
+/// Read the current value of a [`ClockSource`]
+///
+/// Overrides installed with the feature `"mock"` are ignored by this function.
+///
+/// # Errors
+///
+/// Fails with [`ErrorKind::Unsupported`] if the clock is not [implemented](ClockSource::is_implemented)
+/// for the target platform. Clocks that are not [infallible](ClockSource::is_infallible)
+/// can fail for the same reasons as [`utcnow()`](crate::utcnow), e.g. if the kernel is too old to know the clock.
+///
+/// # Example
+///
+/// ```
+/// # use utcnow::{ClockSource, now_from};
+/// let start = now_from(ClockSource::Monotonic).unwrap();
+/// let end = now_from(ClockSource::Monotonic).unwrap();
+/// assert!(end.as_nanos() >= start.as_nanos());
+/// ```
+pub fn now_from(source: ClockSource) -> Result<RawClockReading, ClockError> {
+    if !source.is_implemented() {
+        return Err(ClockError::unsupported(source));
+    }
+    platform::now_from(source)
+}
+
+/// Could not read a [`ClockSource`]
+///
+/// Returned by [`now_from()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClockError {
+    source: ClockSource,
+    kind: ErrorKind,
+    code: Option<i32>,
+}
+
+impl ClockError {
+    #[inline]
+    pub(crate) const fn new(source: ClockSource, kind: ErrorKind, code: Option<i32>) -> Self {
+        Self { source, kind, code }
+    }
+
+    #[inline]
+    pub(crate) const fn unsupported(source: ClockSource) -> Self {
+        Self::new(source, ErrorKind::Unsupported, None)
+    }
+
+    /// The clock that could not be read
+    #[must_use]
+    #[inline]
+    pub const fn source(self) -> ClockSource {
+        self.source
+    }
+
+    /// The category of the error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{ClockSource, ErrorKind, now_from};
+    /// match now_from(ClockSource::Tai) {
+    ///     Ok(reading) => println!("TAI = {}", reading.as_secs()),
+    ///     Err(err) if err.kind() == ErrorKind::Unsupported => println!("No TAI clock"),
+    ///     Err(err) => println!("Could not read the TAI clock: {}", err),
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn kind(self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The raw error code of the operating system, if there is any
+    ///
+    /// See [`Error::raw_os_error()`](crate::Error::raw_os_error).
+    #[must_use]
+    #[inline]
+    pub const fn raw_os_error(self) -> Option<i32> {
+        self.code
+    }
+}
+
+impl fmt::Display for ClockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.kind, self.code) {
+            (_, Some(code)) => write!(
+                f,
+                "could not read clock {:?}: os error {}",
+                self.source, code
+            ),
+            (ErrorKind::Unsupported, None) => {
+                write!(
+                    f,
+                    "clock {:?} is not supported on this platform",
+                    self.source
+                )
+            },
+            (kind, None) => write!(f, "could not read clock {:?}: {}", self.source, kind),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ClockError {}
+
+#[cfg(feature = "std")]
+impl From<ClockError> for std::io::Error {
+    fn from(err: ClockError) -> Self {
+        match err.code {
+            Some(code) => std::io::Error::from_raw_os_error(code),
+            None => std::io::Error::new(crate::io_error_kind(err.kind), err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ClockSource, ErrorKind, now_from};
+
+    const ALL: [ClockSource; 5] = [
+        ClockSource::Realtime,
+        ClockSource::RealtimeCoarse,
+        ClockSource::Tai,
+        ClockSource::Monotonic,
+        ClockSource::Boottime,
+    ];
+
+    #[test]
+    fn test_now_from() {
+        for &source in &ALL {
+            match now_from(source) {
+                Ok(reading) => {
+                    assert!(source.is_implemented());
+                    assert_eq!(reading.source(), source);
+                    assert!(reading.subsec_nanos() < 1_000_000_000);
+                    assert_eq!(reading.to_utc_time().is_some(), source.is_utc());
+                },
+                Err(err) => {
+                    assert!(!source.is_infallible(), "{:?}", source);
+                    assert_eq!(err.source(), source);
+                    if !source.is_implemented() {
+                        assert_eq!(err.kind(), ErrorKind::Unsupported);
+                    }
+                },
+            }
+        }
+
+        assert_eq!(ClockSource::Realtime.is_implemented(), crate::IMPLEMENTED,);
+        assert_eq!(ClockSource::Realtime.is_infallible(), crate::INFALLIBLE);
+    }
+
+    #[test]
+    fn test_monotonic() {
+        if !ClockSource::Monotonic.is_implemented() {
+            return;
+        }
+        let start = now_from(ClockSource::Monotonic).unwrap();
+        let end = now_from(ClockSource::Monotonic).unwrap();
+        assert!(end.as_nanos() >= start.as_nanos());
+        assert!(start.into_duration().is_ok());
+    }
+}
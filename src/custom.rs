@@ -0,0 +1,85 @@
+use core::num::NonZeroU32;
+
+use crate::UtcTime;
+
+/// Register a function to be called by [`utcnow()`](crate::utcnow) on targets without a native implementation
+///
+/// This macro is only available with the feature `"custom"`.
+///
+/// The registered function must have the signature `fn() -> Result<UtcTime, NonZeroU32>`.
+/// The error code is reported by the [`Display`](core::fmt::Display) implementation of [`Error`](crate::Error),
+/// and its [`kind()`](crate::Error::kind) is [`ErrorKind::Other`](crate::ErrorKind::Other).
+///
+/// The macro must be invoked exactly once in the final binary, usually in your application or in
+/// your board support crate. Every library in the dependency tree that calls
+/// [`utcnow()`](crate::utcnow) will then use the registered function.
+///
+/// The custom function is only used if no native implementation exists for the target,
+/// e.g. on bare-metal targets like `thumbv7em-none-eabihf` or `riscv32imac-unknown-none-elf`.
+/// It takes precedence over the [`SystemTime`](std::time::SystemTime) fallback of the feature `"std"`.
+///
+/// # Example
+///
+/// ```
+/// use core::num::NonZeroU32;
+/// use utcnow::UtcTime;
+///
+/// fn read_rtc() -> Result<UtcTime, NonZeroU32> {
+///     // read the time from your real-time clock
+///     UtcTime::new(1_661_201_091, 0).ok_or(NonZeroU32::new(1).unwrap())
+/// }
+///
+/// utcnow::register_custom_utcnow!(read_rtc);
+/// ```
+#[macro_export]
+macro_rules! register_custom_utcnow {
+    ($path:path $(,)?) => {
+        const _: () = {
+            #[no_mangle]
+            extern "Rust" fn __utcnow_custom()
+            -> ::core::result::Result<$crate::UtcTime, ::core::num::NonZeroU32> {
+                let f: fn() -> ::core::result::Result<$crate::UtcTime, ::core::num::NonZeroU32> =
+                    $path;
+                f()
+            }
+        };
+    };
+}
+
+extern "Rust" {
+    fn __utcnow_custom() -> Result<UtcTime, NonZeroU32>;
+}
+
+/// Call the function registered with [`register_custom_utcnow!`]
+#[allow(dead_code)] // unused if the target has a native implementation
+#[inline]
+pub(crate) fn utcnow() -> Result<UtcTime, NonZeroU32> {
+    // SAFETY: the symbol is defined by `register_custom_utcnow!()` with a matching signature
+    unsafe { __utcnow_custom() }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use crate::UtcTime;
+
+    fn read_rtc() -> Result<UtcTime, NonZeroU32> {
+        UtcTime::new(1_661_201_091, 0).ok_or(NonZeroU32::MIN)
+    }
+
+    crate::register_custom_utcnow!(read_rtc);
+
+    #[test]
+    fn test_custom() {
+        assert_eq!(super::utcnow().unwrap().as_secs(), 1_661_201_091);
+    }
+
+    #[cfg(utcnow_force_custom)]
+    #[test]
+    fn test_forced_custom() {
+        assert!(crate::IMPLEMENTED);
+        assert!(!crate::INFALLIBLE);
+        assert_eq!(crate::utcnow().unwrap().as_secs(), 1_661_201_091);
+    }
+}
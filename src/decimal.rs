@@ -0,0 +1,246 @@
+use core::fmt;
+
+use crate::{ConversionError, MAX_SECS_DIGITS, UtcTime};
+
+/// A [`UtcTime`] that is [displayed](fmt::Display) as a signed decimal number of seconds
+///
+/// Use [`UtcTime::to_decimal()`] to get an instance.
+///
+/// Unlike the [`Display`](fmt::Display) implementation of [`UtcTime`], which writes the whole seconds
+/// and the nanoseconds independently, the text denotes the real value of the timestamp even before 1970,
+/// e.g. `-0.500000000` for half a second before the epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Decimal(UtcTime);
+
+impl UtcTime {
+    /// Format the timestamp as signed decimal number of seconds since epoch (1970-01-01 in UTC)
+    ///
+    /// The inverse operation is [`UtcTime::from_decimal()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let timestamp = UtcTime::new(-2, 500_000_000).unwrap();
+    /// assert_eq!(timestamp.to_decimal().to_string(), "-1.500000000");
+    ///
+    /// // For compatibility the `Display` implementation prints whole seconds and nanoseconds independently:
+    /// assert_eq!(timestamp.to_string(), "-2.500000000");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_decimal(self) -> Decimal {
+        Decimal(self)
+    }
+
+    /// Parse a signed decimal number of seconds since epoch (1970-01-01 in UTC)
+    ///
+    /// The input may start with `+` or `-`, and may omit either the whole or the fractional seconds,
+    /// but not both. Fractional seconds with more than nine digits are truncated towards zero.
+    ///
+    /// Unlike [`FromStr`](core::str::FromStr) for [`UtcTime`], the sign applies to the whole number,
+    /// so `"-1.5"` is one and a half seconds before the epoch.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is malformed, or if the timestamp is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let timestamp = UtcTime::from_decimal("-1.5").unwrap();
+    /// assert_eq!(timestamp.as_nanos(), -1_500_000_000);
+    /// assert_eq!(timestamp, UtcTime::new(-2, 500_000_000).unwrap());
+    /// ```
+    #[const_fn::const_fn("1.56")]
+    pub fn from_decimal(value: &str) -> Result<Self, ConversionError> {
+        parse(value.as_bytes())
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let UtcTime { secs, nanos } = self.0;
+        match nanos.get() {
+            nanos if secs < 0 && nanos > 0 => {
+                // -(secs + 1) cannot overflow
+                write!(f, "-{}.{:09}", -(secs + 1), 1_000_000_000 - nanos)
+            },
+            nanos => write!(f, "{}.{:09}", secs, nanos),
+        }
+    }
+}
+
+#[allow(clippy::cast_lossless)]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+#[const_fn::const_fn("1.56")]
+const fn parse(s: &[u8]) -> Result<UtcTime, ConversionError> {
+    let (negative, mut pos) = match s.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+
+    let mut digits = 0;
+    let mut secs: i128 = 0;
+    while pos < s.len() && s[pos].is_ascii_digit() {
+        secs = secs * 10 + (s[pos] - b'0') as i128;
+        if secs > MAX_SECS_DIGITS {
+            return Err(ConversionError::TOO_MANY_DIGITS);
+        }
+        digits += 1;
+        pos += 1;
+    }
+
+    let mut nanos = 0;
+    if pos < s.len() && s[pos] == b'.' {
+        pos += 1;
+        let mut factor = 100_000_000;
+        while pos < s.len() && s[pos].is_ascii_digit() {
+            nanos += (s[pos] - b'0') as u32 * factor;
+            factor /= 10;
+            digits += 1;
+            pos += 1;
+        }
+    }
+    if pos != s.len() {
+        return Err(ConversionError::invalid_character(pos));
+    } else if digits == 0 {
+        return Err(ConversionError::EMPTY);
+    }
+
+    let (secs, nanos) = match (negative, nanos) {
+        (false, nanos) => (secs, nanos),
+        (true, 0) => (-secs, 0),
+        (true, nanos) => (-secs - 1, 1_000_000_000 - nanos),
+    };
+    if secs < i64::MIN as i128 || secs > i64::MAX as i128 {
+        return Err(ConversionError::OVERFLOW);
+    }
+    Ok(unsafe { UtcTime::new_unchecked(secs as i64, nanos) })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ConversionErrorKind, UtcTime};
+
+    /// Independent implementation using [`UtcTime::as_nanos()`]
+    fn expected(value: UtcTime) -> String {
+        let nanos = value.as_nanos();
+        let sign = if nanos < 0 { "-" } else { "" };
+        let nanos = nanos.unsigned_abs();
+        format!(
+            "{}{}.{:09}",
+            sign,
+            nanos / 1_000_000_000,
+            nanos % 1_000_000_000,
+        )
+    }
+
+    fn check(value: UtcTime) {
+        let string = value.to_decimal().to_string();
+        assert_eq!(string, expected(value));
+        assert_eq!(UtcTime::from_decimal(&string), Ok(value), "{}", string);
+    }
+
+    #[test]
+    fn test_decimal() {
+        for &(secs, nanos) in &[
+            (0, 0),
+            (0, 1),
+            (-1, 0),
+            (-1, 1),
+            (-1, 500_000_000),
+            (-1, 999_999_999),
+            (-2, 500_000_000),
+            (1_661_201_091, 326_474_702),
+            (-1_661_201_091, 326_474_702),
+            (i64::MIN, 0),
+            (i64::MIN, 1),
+            (i64::MAX, 999_999_999),
+        ] {
+            check(UtcTime::new(secs, nanos).unwrap());
+        }
+
+        assert_eq!(
+            UtcTime::new(-1, 500_000_000)
+                .unwrap()
+                .to_decimal()
+                .to_string(),
+            "-0.500000000",
+        );
+        assert_eq!(
+            UtcTime::MIN.to_decimal().to_string(),
+            "-9223372036854775808.000000000"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |s| UtcTime::from_decimal(s).map(UtcTime::as_nanos);
+        assert_eq!(parse("-1.5"), Ok(-1_500_000_000));
+        assert_eq!(parse("-.5"), Ok(-500_000_000));
+        assert_eq!(parse("-0"), Ok(0));
+        assert_eq!(parse("-0.000000001"), Ok(-1));
+        assert_eq!(parse("-0.0000000019"), Ok(-1));
+        assert_eq!(parse("+1."), Ok(1_000_000_000));
+        assert_eq!(parse("1661201091.326474702"), Ok(1_661_201_091_326_474_702));
+        assert_eq!(
+            parse("-9223372036854775808"),
+            Ok(i128::from(i64::MIN) * 1_000_000_000),
+        );
+
+        for &input in &[
+            "",
+            ".",
+            "-",
+            "+",
+            "-.",
+            "--1",
+            "+-1",
+            " 1",
+            "1 ",
+            "1.5e3",
+            "1.-5",
+            "9223372036854775808",
+            "-9223372036854775808.1",
+            "99999999999999999999999999999999999999999",
+        ] {
+            assert!(UtcTime::from_decimal(input).is_err(), "{:?}", input);
+        }
+
+        let kind = |s| UtcTime::from_decimal(s).unwrap_err().kind();
+        assert_eq!(kind(""), ConversionErrorKind::Empty);
+        assert_eq!(kind("-."), ConversionErrorKind::Empty);
+        assert_eq!(kind("+-1"), ConversionErrorKind::InvalidCharacter {
+            index: 1
+        });
+        assert_eq!(kind("1.5e3"), ConversionErrorKind::InvalidCharacter {
+            index: 3
+        });
+        assert_eq!(kind("9223372036854775808"), ConversionErrorKind::Overflow);
+        assert_eq!(
+            kind("-9223372036854775808.1"),
+            ConversionErrorKind::Overflow
+        );
+        assert_eq!(
+            kind("10000000000000000000"),
+            ConversionErrorKind::TooManyDigits
+        );
+    }
+
+    #[cfg(all(feature = "proptest", not(miri)))]
+    proptest::proptest! {
+        #[test]
+        fn test_round_trip(value: UtcTime) {
+            check(value);
+        }
+
+        #[test]
+        fn test_round_trip_negative(secs in i64::MIN..0, nanos in 0..1_000_000_000_u32) {
+            check(UtcTime::new(secs, nanos).unwrap());
+        }
+    }
+}
@@ -0,0 +1,673 @@
+use core::convert::TryFrom;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use core::time::Duration;
+
+use crate::u30::U30;
+use crate::{ConversionError, UtcTime};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// A signed span of time, i.e. the difference between two [`UtcTime`]s
+///
+/// Just like [`UtcTime`] the value is stored as whole seconds plus a non-negative number of
+/// nanoseconds, so `-0.5 s` is represented as `-1 s + 500_000_000 ns`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcDelta {
+    /// Whole seconds, rounded towards negative infinity
+    pub(crate) secs: i64,
+    /// Nanoseconds to add to `secs`
+    pub(crate) nanos: U30,
+}
+
+impl UtcDelta {
+    /// The most positive representable span of time
+    pub const MAX: UtcDelta = UtcDelta {
+        secs: i64::MAX,
+        nanos: U30::MAX,
+    };
+    /// The most negative representable span of time
+    pub const MIN: UtcDelta = UtcDelta {
+        secs: i64::MIN,
+        nanos: U30::ZERO,
+    };
+    /// An empty span of time
+    pub const ZERO: UtcDelta = UtcDelta {
+        secs: 0,
+        nanos: U30::ZERO,
+    };
+
+    /// Build a new [`UtcDelta`] without normalization
+    ///
+    /// # Safety
+    ///
+    /// The same rules as for [`UtcTime::new_unchecked()`] apply:
+    /// `nanos` must be less than `1_000_000_000`.
+    #[inline]
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub unsafe fn new_unchecked(secs: i64, nanos: u32) -> Self {
+        let nanos = U30::new_unchecked(nanos);
+        Self { secs, nanos }
+    }
+
+    /// Build a new [`UtcDelta`]
+    ///
+    /// The value denotes `secs + nanos / 1_000_000_000` seconds.
+    /// `nanos` will be normalized to a values less than `1_000_000_000`.
+    /// If the resulting number of seconds will exceed [`i64::MAX`], [`None`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcDelta;
+    /// // -0.5 seconds
+    /// let delta = UtcDelta::new(-1, 500_000_000).unwrap();
+    /// assert_eq!(delta.as_nanos(), -500_000_000);
+    /// ```
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn new(secs: i64, nanos: u32) -> Option<Self> {
+        match narrow(
+            secs as i128 + (nanos / NANOS_PER_SEC) as i128,
+            nanos % NANOS_PER_SEC,
+        ) {
+            Some((secs, nanos)) => Some(unsafe { Self::new_unchecked(secs, nanos) }),
+            None => None,
+        }
+    }
+
+    /// Convert a non-negative [Duration]
+    ///
+    /// Returns [`None`] if the duration exceeds [`UtcDelta::MAX`].
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn from_duration(value: Duration) -> Option<Self> {
+        match narrow(value.as_secs() as i128, value.subsec_nanos()) {
+            Some((secs, nanos)) => Some(unsafe { Self::new_unchecked(secs, nanos) }),
+            None => None,
+        }
+    }
+
+    /// Whole seconds of the span, rounded towards negative infinity
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcDelta;
+    /// let delta = UtcDelta::new(-1, 500_000_000).unwrap();
+    /// assert_eq!(delta.as_secs(), -1);
+    /// assert_eq!(delta.subsec_nanos(), 500_000_000);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_secs(self) -> i64 {
+        self.secs
+    }
+
+    /// Nanoseconds to add to [`as_secs()`](UtcDelta::as_secs) to get the full span
+    #[must_use]
+    #[inline]
+    #[const_fn::const_fn("1.56")]
+    pub fn subsec_nanos(self) -> u32 {
+        self.nanos.get()
+    }
+
+    /// Total number of nanoseconds in the span
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn as_nanos(self) -> i128 {
+        (self.secs as i128 * NANOS_PER_SEC as i128) + (self.nanos.get() as i128)
+    }
+
+    /// `true` if the span is less than zero
+    #[must_use]
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.secs < 0
+    }
+
+    /// The absolute value of the span
+    ///
+    /// This conversion cannot fail, not even for [`UtcDelta::MIN`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// # use utcnow::UtcDelta;
+    /// let delta = UtcDelta::new(-1, 500_000_000).unwrap();
+    /// assert_eq!(delta.unsigned_abs(), Duration::from_millis(500));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.58")]
+    pub fn unsigned_abs(self) -> Duration {
+        let (secs, nanos) = if self.secs >= 0 {
+            (self.secs as i128, self.nanos.get())
+        } else {
+            neg_parts(self.secs, self.nanos.get())
+        };
+        Duration::new(secs as u64, nanos)
+    }
+
+    /// Convert the span to a [Duration]
+    ///
+    /// # Errors
+    ///
+    /// Fails if the span is negative.
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.58")]
+    pub fn into_duration(self) -> Result<Duration, ConversionError> {
+        if self.secs < 0 {
+            return Err(ConversionError::NEGATIVE);
+        }
+        Ok(Duration::new(self.secs as u64, self.nanos.get()))
+    }
+
+    /// Negate the span, returning [`None`] for [`UtcDelta::MIN`]
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn checked_neg(self) -> Option<Self> {
+        let (secs, nanos) = neg_parts(self.secs, self.nanos.get());
+        match narrow(secs, nanos) {
+            Some((secs, nanos)) => Some(unsafe { Self::new_unchecked(secs, nanos) }),
+            None => None,
+        }
+    }
+
+    /// Add two spans, returning [`None`] on overflow
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (secs, nanos) = add_parts(
+            self.secs as i128,
+            self.nanos.get(),
+            rhs.secs as i128,
+            rhs.nanos.get(),
+        );
+        match narrow(secs, nanos) {
+            Some((secs, nanos)) => Some(unsafe { Self::new_unchecked(secs, nanos) }),
+            None => None,
+        }
+    }
+
+    /// Subtract two spans, returning [`None`] on overflow
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (secs, nanos) = sub_parts(
+            self.secs as i128,
+            self.nanos.get(),
+            rhs.secs as i128,
+            rhs.nanos.get(),
+        );
+        match narrow(secs, nanos) {
+            Some((secs, nanos)) => Some(unsafe { Self::new_unchecked(secs, nanos) }),
+            None => None,
+        }
+    }
+
+    /// Negate the span, returning [`UtcDelta::MAX`] for [`UtcDelta::MIN`]
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn saturating_neg(self) -> Self {
+        match self.checked_neg() {
+            Some(value) => value,
+            None => Self::MAX,
+        }
+    }
+
+    /// Add two spans, clamping the result to [`UtcDelta::MIN`]..=[`UtcDelta::MAX`]
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(value) => value,
+            None if rhs.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Subtract two spans, clamping the result to [`UtcDelta::MIN`]..=[`UtcDelta::MAX`]
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(value) => value,
+            None if rhs.is_negative() => Self::MAX,
+            None => Self::MIN,
+        }
+    }
+}
+
+impl UtcTime {
+    /// Add a [`UtcDelta`], returning [`None`] on overflow
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{UtcDelta, UtcTime};
+    /// let delta = UtcDelta::new(-1, 500_000_000).unwrap();
+    /// let timestamp = UtcTime::EPOCH.checked_add(delta).unwrap();
+    /// assert_eq!(timestamp.as_nanos(), -500_000_000);
+    /// ```
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn checked_add(self, rhs: UtcDelta) -> Option<Self> {
+        let (secs, nanos) = add_parts(
+            self.secs as i128,
+            self.nanos.get(),
+            rhs.secs as i128,
+            rhs.nanos.get(),
+        );
+        match narrow(secs, nanos) {
+            Some((secs, nanos)) => Some(unsafe { Self::new_unchecked(secs, nanos) }),
+            None => None,
+        }
+    }
+
+    /// Subtract a [`UtcDelta`], returning [`None`] on overflow
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn checked_sub(self, rhs: UtcDelta) -> Option<Self> {
+        let (secs, nanos) = sub_parts(
+            self.secs as i128,
+            self.nanos.get(),
+            rhs.secs as i128,
+            rhs.nanos.get(),
+        );
+        match narrow(secs, nanos) {
+            Some((secs, nanos)) => Some(unsafe { Self::new_unchecked(secs, nanos) }),
+            None => None,
+        }
+    }
+
+    /// Add a [`Duration`], returning [`None`] on overflow
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// # use utcnow::UtcTime;
+    /// let timestamp = UtcTime::new(-1, 0).unwrap();
+    /// let timestamp = timestamp.checked_add_duration(Duration::from_millis(1500)).unwrap();
+    /// assert_eq!(timestamp, UtcTime::new(0, 500_000_000).unwrap());
+    /// ```
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn checked_add_duration(self, rhs: Duration) -> Option<Self> {
+        let (secs, nanos) = add_parts(
+            self.secs as i128,
+            self.nanos.get(),
+            rhs.as_secs() as i128,
+            rhs.subsec_nanos(),
+        );
+        match narrow(secs, nanos) {
+            Some((secs, nanos)) => Some(unsafe { Self::new_unchecked(secs, nanos) }),
+            None => None,
+        }
+    }
+
+    /// Subtract a [`Duration`], returning [`None`] on overflow
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn checked_sub_duration(self, rhs: Duration) -> Option<Self> {
+        let (secs, nanos) = sub_parts(
+            self.secs as i128,
+            self.nanos.get(),
+            rhs.as_secs() as i128,
+            rhs.subsec_nanos(),
+        );
+        match narrow(secs, nanos) {
+            Some((secs, nanos)) => Some(unsafe { Self::new_unchecked(secs, nanos) }),
+            None => None,
+        }
+    }
+
+    /// The signed span of time from `earlier` to `self`, returning [`None`] on overflow
+    ///
+    /// The result is negative if `earlier` is actually later than `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let a = UtcTime::new(-1, 0).unwrap();
+    /// let b = UtcTime::new(0, 500_000_000).unwrap();
+    /// assert_eq!(b.checked_delta_since(a).unwrap().as_nanos(), 1_500_000_000);
+    /// assert_eq!(a.checked_delta_since(b).unwrap().as_nanos(), -1_500_000_000);
+    /// ```
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn checked_delta_since(self, earlier: UtcTime) -> Option<UtcDelta> {
+        let (secs, nanos) = sub_parts(
+            self.secs as i128,
+            self.nanos.get(),
+            earlier.secs as i128,
+            earlier.nanos.get(),
+        );
+        match narrow(secs, nanos) {
+            Some((secs, nanos)) => Some(unsafe { UtcDelta::new_unchecked(secs, nanos) }),
+            None => None,
+        }
+    }
+
+    /// Add a [`UtcDelta`], clamping the result to [`UtcTime::MIN`]..=[`UtcTime::MAX`]
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn saturating_add(self, rhs: UtcDelta) -> Self {
+        match self.checked_add(rhs) {
+            Some(value) => value,
+            None if rhs.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Subtract a [`UtcDelta`], clamping the result to [`UtcTime::MIN`]..=[`UtcTime::MAX`]
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn saturating_sub(self, rhs: UtcDelta) -> Self {
+        match self.checked_sub(rhs) {
+            Some(value) => value,
+            None if rhs.is_negative() => Self::MAX,
+            None => Self::MIN,
+        }
+    }
+
+    /// Add a [`Duration`], clamping the result to [`UtcTime::MAX`]
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn saturating_add_duration(self, rhs: Duration) -> Self {
+        match self.checked_add_duration(rhs) {
+            Some(value) => value,
+            None => Self::MAX,
+        }
+    }
+
+    /// Subtract a [`Duration`], clamping the result to [`UtcTime::MIN`]
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn saturating_sub_duration(self, rhs: Duration) -> Self {
+        match self.checked_sub_duration(rhs) {
+            Some(value) => value,
+            None => Self::MIN,
+        }
+    }
+
+    /// The signed span of time from `earlier` to `self`, clamped to [`UtcDelta::MIN`]..=[`UtcDelta::MAX`]
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn saturating_delta_since(self, earlier: UtcTime) -> UtcDelta {
+        match self.checked_delta_since(earlier) {
+            Some(value) => value,
+            None if self.secs < earlier.secs => UtcDelta::MIN,
+            None => UtcDelta::MAX,
+        }
+    }
+}
+
+/// Narrow a normalized `(secs, nanos)` pair to the `i64` range
+#[allow(clippy::cast_possible_truncation)]
+const fn narrow(secs: i128, nanos: u32) -> Option<(i64, u32)> {
+    if secs < i64::MIN as i128 || secs > i64::MAX as i128 {
+        None
+    } else {
+        Some((secs as i64, nanos))
+    }
+}
+
+const fn add_parts(a_secs: i128, a_nanos: u32, b_secs: i128, b_nanos: u32) -> (i128, u32) {
+    let nanos = a_nanos + b_nanos;
+    if nanos >= NANOS_PER_SEC {
+        (a_secs + b_secs + 1, nanos - NANOS_PER_SEC)
+    } else {
+        (a_secs + b_secs, nanos)
+    }
+}
+
+const fn sub_parts(a_secs: i128, a_nanos: u32, b_secs: i128, b_nanos: u32) -> (i128, u32) {
+    if a_nanos >= b_nanos {
+        (a_secs - b_secs, a_nanos - b_nanos)
+    } else {
+        (a_secs - b_secs - 1, a_nanos + NANOS_PER_SEC - b_nanos)
+    }
+}
+
+const fn neg_parts(secs: i64, nanos: u32) -> (i128, u32) {
+    sub_parts(0, 0, secs as i128, nanos)
+}
+
+impl Neg for UtcDelta {
+    type Output = UtcDelta;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        match self.checked_neg() {
+            Some(value) => value,
+            None => panic!("overflow when negating UtcDelta"),
+        }
+    }
+}
+
+impl Add for UtcDelta {
+    type Output = UtcDelta;
+
+    #[inline]
+    fn add(self, rhs: UtcDelta) -> Self::Output {
+        match self.checked_add(rhs) {
+            Some(value) => value,
+            None => panic!("overflow when adding UtcDeltas"),
+        }
+    }
+}
+
+impl Sub for UtcDelta {
+    type Output = UtcDelta;
+
+    #[inline]
+    fn sub(self, rhs: UtcDelta) -> Self::Output {
+        match self.checked_sub(rhs) {
+            Some(value) => value,
+            None => panic!("overflow when subtracting UtcDeltas"),
+        }
+    }
+}
+
+impl AddAssign for UtcDelta {
+    #[inline]
+    fn add_assign(&mut self, rhs: UtcDelta) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for UtcDelta {
+    #[inline]
+    fn sub_assign(&mut self, rhs: UtcDelta) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<UtcDelta> for UtcTime {
+    type Output = UtcTime;
+
+    #[inline]
+    fn add(self, rhs: UtcDelta) -> Self::Output {
+        match self.checked_add(rhs) {
+            Some(value) => value,
+            None => panic!("overflow when adding UtcDelta to UtcTime"),
+        }
+    }
+}
+
+impl Sub<UtcDelta> for UtcTime {
+    type Output = UtcTime;
+
+    #[inline]
+    fn sub(self, rhs: UtcDelta) -> Self::Output {
+        match self.checked_sub(rhs) {
+            Some(value) => value,
+            None => panic!("overflow when subtracting UtcDelta from UtcTime"),
+        }
+    }
+}
+
+impl AddAssign<UtcDelta> for UtcTime {
+    #[inline]
+    fn add_assign(&mut self, rhs: UtcDelta) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<UtcDelta> for UtcTime {
+    #[inline]
+    fn sub_assign(&mut self, rhs: UtcDelta) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<Duration> for UtcTime {
+    type Output = UtcTime;
+
+    #[inline]
+    fn add(self, rhs: Duration) -> Self::Output {
+        match self.checked_add_duration(rhs) {
+            Some(value) => value,
+            None => panic!("overflow when adding Duration to UtcTime"),
+        }
+    }
+}
+
+impl Sub<Duration> for UtcTime {
+    type Output = UtcTime;
+
+    #[inline]
+    fn sub(self, rhs: Duration) -> Self::Output {
+        match self.checked_sub_duration(rhs) {
+            Some(value) => value,
+            None => panic!("overflow when subtracting Duration from UtcTime"),
+        }
+    }
+}
+
+impl AddAssign<Duration> for UtcTime {
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Duration> for UtcTime {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for UtcTime {
+    type Output = UtcDelta;
+
+    #[inline]
+    fn sub(self, rhs: UtcTime) -> Self::Output {
+        match self.checked_delta_since(rhs) {
+            Some(value) => value,
+            None => panic!("overflow when subtracting UtcTimes"),
+        }
+    }
+}
+
+impl TryFrom<Duration> for UtcDelta {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: Duration) -> Result<Self, ConversionError> {
+        Self::from_duration(value).ok_or(ConversionError::OVERFLOW)
+    }
+}
+
+impl TryFrom<UtcDelta> for Duration {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: UtcDelta) -> Result<Self, ConversionError> {
+        value.into_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use crate::{UtcDelta, UtcTime};
+
+    #[test]
+    fn test_delta_new() {
+        let delta = UtcDelta::new(-1, 1_500_000_000).unwrap();
+        assert_eq!(delta.as_nanos(), 500_000_000);
+        assert_eq!(UtcDelta::new(i64::MAX, 1_000_000_000), None);
+        assert_eq!(
+            UtcDelta::MIN.as_nanos(),
+            i128::from(i64::MIN) * 1_000_000_000
+        );
+        assert_eq!(UtcDelta::MIN.checked_neg(), None);
+        assert_eq!(UtcDelta::MIN.saturating_neg(), UtcDelta::MAX);
+        assert_eq!(UtcDelta::MIN.unsigned_abs(), Duration::new(1 << 63, 0),);
+        assert_eq!((-UtcDelta::MAX).as_nanos(), -UtcDelta::MAX.as_nanos(),);
+    }
+
+    #[test]
+    fn test_time_arithmetic() {
+        let a = UtcTime::new(-1, 250_000_000).unwrap();
+        let b = UtcTime::new(2, 750_000_000).unwrap();
+
+        let delta = b - a;
+        assert_eq!(delta.as_nanos(), 3_500_000_000);
+        assert_eq!((a - b).as_nanos(), -3_500_000_000);
+        assert_eq!(a + delta, b);
+        assert_eq!(b - delta, a);
+        assert_eq!(b + -delta, a);
+
+        assert_eq!(a + Duration::from_millis(3500), b);
+        assert_eq!(b - Duration::from_millis(3500), a);
+
+        let mut c = a;
+        c += Duration::from_millis(750);
+        assert_eq!(c.as_nanos(), 0);
+        c -= UtcDelta::new(0, 1).unwrap();
+        assert_eq!(c.as_nanos(), -1);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let one = UtcDelta::new(0, 1).unwrap();
+        assert_eq!(UtcTime::MAX.checked_add(one), None);
+        assert_eq!(UtcTime::MIN.checked_sub(one), None);
+        assert_eq!(UtcTime::MAX.saturating_add(one), UtcTime::MAX);
+        assert_eq!(UtcTime::MIN.saturating_sub(one), UtcTime::MIN);
+        assert_eq!(UtcTime::MIN.saturating_add(-one), UtcTime::MIN);
+        assert_eq!(UtcTime::MAX.saturating_sub(-one), UtcTime::MAX);
+
+        let long = Duration::new(u64::MAX, 999_999_999);
+        assert_eq!(UtcTime::MIN.checked_add_duration(long), Some(UtcTime::MAX));
+        assert_eq!(UtcTime::MAX.checked_sub_duration(long), Some(UtcTime::MIN));
+        assert_eq!(UtcTime::EPOCH.checked_add_duration(long), None);
+        assert_eq!(UtcTime::EPOCH.saturating_add_duration(long), UtcTime::MAX);
+        assert_eq!(UtcTime::EPOCH.saturating_sub_duration(long), UtcTime::MIN);
+
+        assert_eq!(UtcTime::MAX.checked_delta_since(UtcTime::MIN), None);
+        assert_eq!(
+            UtcTime::MAX.saturating_delta_since(UtcTime::MIN),
+            UtcDelta::MAX,
+        );
+        assert_eq!(
+            UtcTime::MIN.saturating_delta_since(UtcTime::MAX),
+            UtcDelta::MIN,
+        );
+        assert_eq!(
+            UtcTime::MIN.checked_delta_since(UtcTime::EPOCH),
+            Some(UtcDelta::MIN),
+        );
+    }
+}
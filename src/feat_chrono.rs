@@ -0,0 +1,75 @@
+use core::convert::TryFrom;
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::{ConversionError, UtcTime};
+
+/// A leap second, i.e. a fractional second of 1 000 000 000 nanoseconds or more,
+/// is folded into the next second, like in [`UtcTime::parse_rfc3339()`]
+impl<Tz: TimeZone> From<DateTime<Tz>> for UtcTime {
+    fn from(value: DateTime<Tz>) -> Self {
+        let secs = value.timestamp();
+        let nanos = value.timestamp_subsec_nanos();
+        // `DateTime` is limited to about ±262 000 years, so the seconds cannot overflow
+        if nanos >= 1_000_000_000 {
+            unsafe { Self::new_unchecked(secs + 1, nanos - 1_000_000_000) }
+        } else {
+            unsafe { Self::new_unchecked(secs, nanos) }
+        }
+    }
+}
+
+/// Fails if the time is outside of the range of [`DateTime<Utc>`], about ±262 000 years
+impl TryFrom<UtcTime> for DateTime<Utc> {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: UtcTime) -> Result<Self, ConversionError> {
+        DateTime::from_timestamp(value.secs, value.nanos.get()).ok_or(ConversionError::OVERFLOW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+
+    use crate::{ConversionErrorKind, UtcTime};
+
+    #[test]
+    fn test_chrono() {
+        let time = UtcTime::new(-1_659_545_693, 895_531_827).unwrap();
+        let datetime = DateTime::<Utc>::try_from(time).unwrap();
+        assert_eq!(datetime.to_string(), "1917-05-31 07:05:07.895531827 UTC");
+        assert_eq!(time.rfc3339().to_string(), "1917-05-31T07:05:07.895531827Z");
+        assert_eq!(UtcTime::from(datetime), time);
+
+        let offset = datetime.with_timezone(&FixedOffset::east_opt(3600).unwrap());
+        assert_eq!(UtcTime::from(offset), time);
+
+        let min = UtcTime::from(DateTime::<Utc>::MIN_UTC);
+        let max = UtcTime::from(DateTime::<Utc>::MAX_UTC);
+        assert_eq!(DateTime::<Utc>::try_from(min), Ok(DateTime::<Utc>::MIN_UTC));
+        assert_eq!(DateTime::<Utc>::try_from(max), Ok(DateTime::<Utc>::MAX_UTC));
+        for &time in &[
+            UtcTime::new(min.as_secs() - 1, 999_999_999).unwrap(),
+            UtcTime::new(max.as_secs() + 1, 0).unwrap(),
+            UtcTime::MIN,
+            UtcTime::MAX,
+        ] {
+            let err = DateTime::<Utc>::try_from(time).unwrap_err();
+            assert_eq!(err.kind(), ConversionErrorKind::Overflow);
+        }
+
+        // 2016-12-31T23:59:60.5Z
+        let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+            .unwrap()
+            .and_utc();
+        let expected = UtcTime::parse_rfc3339("2016-12-31T23:59:60.5Z").unwrap();
+        assert_eq!(UtcTime::from(leap), expected);
+        assert_eq!(expected.rfc3339().to_string(), "2017-01-01T00:00:00.5Z");
+    }
+}
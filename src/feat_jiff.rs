@@ -0,0 +1,75 @@
+use core::convert::TryFrom;
+
+use jiff::Timestamp;
+
+use crate::{ConversionError, UtcTime};
+
+impl From<Timestamp> for UtcTime {
+    #[inline]
+    fn from(value: Timestamp) -> Self {
+        // `Timestamp` truncates towards zero, `UtcTime` rounds down
+        let secs = value.as_second();
+        let nanos = value.subsec_nanosecond();
+        if nanos < 0 {
+            #[allow(clippy::cast_sign_loss)] // `nanos` is in -999_999_999..=-1
+            unsafe {
+                Self::new_unchecked(secs - 1, (nanos + 1_000_000_000) as u32)
+            }
+        } else {
+            #[allow(clippy::cast_sign_loss)] // `nanos` is in 0..=999_999_999
+            unsafe {
+                Self::new_unchecked(secs, nanos as u32)
+            }
+        }
+    }
+}
+
+/// Fails if the time is outside of the range of [`Timestamp`], i.e. the years -9999 to 9999
+impl TryFrom<UtcTime> for Timestamp {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: UtcTime) -> Result<Self, ConversionError> {
+        // Check the range first: `Timestamp::MIN` is a whole second, and `Timestamp::MAX` ends a second.
+        if !(Self::MIN.as_second()..=Self::MAX.as_second()).contains(&value.secs) {
+            return Err(ConversionError::OVERFLOW);
+        }
+        Self::from_nanosecond(value.as_nanos()).map_err(|_| ConversionError::OVERFLOW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use jiff::Timestamp;
+
+    use crate::{ConversionErrorKind, UtcTime};
+
+    #[test]
+    fn test_jiff() {
+        let time = UtcTime::new(-1_659_545_693, 895_531_827).unwrap();
+        let timestamp = Timestamp::try_from(time).unwrap();
+        assert_eq!(timestamp.as_second(), -1_659_545_692);
+        assert_eq!(timestamp.subsec_nanosecond(), -104_468_173);
+        assert_eq!(timestamp.as_nanosecond(), time.as_nanos());
+        assert_eq!(UtcTime::from(timestamp), time);
+
+        let time = UtcTime::new(-1, 0).unwrap();
+        assert_eq!(UtcTime::from(Timestamp::try_from(time).unwrap()), time);
+
+        let min = UtcTime::from(Timestamp::MIN);
+        let max = UtcTime::from(Timestamp::MAX);
+        assert_eq!(Timestamp::try_from(min), Ok(Timestamp::MIN));
+        assert_eq!(Timestamp::try_from(max), Ok(Timestamp::MAX));
+        for &time in &[
+            UtcTime::new(min.as_secs() - 1, 999_999_999).unwrap(),
+            UtcTime::new(max.as_secs() + 1, 0).unwrap(),
+            UtcTime::MIN,
+            UtcTime::MAX,
+        ] {
+            let err = Timestamp::try_from(time).unwrap_err();
+            assert_eq!(err.kind(), ConversionErrorKind::Overflow);
+        }
+    }
+}
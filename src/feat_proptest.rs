@@ -23,3 +23,176 @@ proptest::proptest! {
         assert!(value.nanos.get() < 1_000_000_000);
     }
 }
+
+proptest::proptest! {
+    #[cfg(all(test, not(miri)))]
+    #[test]
+    fn from_units_round_trip(value: crate::UtcTime) {
+        use crate::UtcTime;
+
+        assert_eq!(UtcTime::from_nanos(value.as_nanos()), Some(value));
+
+        let secs = UtcTime::from_secs(value.as_secs());
+        let millis = UtcTime::from_millis(value.as_millis()).unwrap();
+        let micros = UtcTime::from_micros(value.as_micros()).unwrap();
+        assert_eq!(millis.as_millis(), value.as_millis());
+        assert_eq!(micros.as_micros(), value.as_micros());
+        assert!(secs <= millis && millis <= micros && micros <= value);
+        assert_eq!(millis.subsec_nanos(), value.subsec_millis() * 1_000_000);
+        assert_eq!(micros.subsec_nanos(), value.subsec_micros() * 1_000);
+    }
+
+    #[cfg(all(test, not(miri)))]
+    #[test]
+    fn from_units_any(value: i128) {
+        use core::convert::TryFrom;
+
+        use crate::UtcTime;
+
+        for &(time, per_sec) in &[
+            (UtcTime::from_millis(value), 1_000),
+            (UtcTime::from_micros(value), 1_000_000),
+            (UtcTime::from_nanos(value), 1_000_000_000),
+        ] {
+            let in_range = i64::try_from(value.div_euclid(per_sec)).is_ok();
+            assert_eq!(time.is_some(), in_range);
+            if let Some(time) = time {
+                let nanos = time.as_nanos();
+                assert_eq!(nanos / (1_000_000_000 / per_sec), value);
+            }
+        }
+    }
+
+    #[cfg(all(test, not(miri)))]
+    #[test]
+    fn secs_f64_round_trip(secs in -(1_i64 << 20)..(1_i64 << 20), nanos in 0..1_000_000_000_u32) {
+        use crate::UtcTime;
+
+        let value = UtcTime::new(secs, nanos).unwrap();
+        let back = UtcTime::from_secs_f64(value.as_secs_f64()).unwrap();
+        let diff = back.as_nanos() - value.as_nanos();
+        assert!((-1..=1).contains(&diff), "{:?} != {:?}", value, back);
+    }
+
+    #[cfg(all(test, not(miri)))]
+    #[test]
+    fn secs_f64_floor(value: f64) {
+        use crate::UtcTime;
+
+        if let Some(time) = UtcTime::from_secs_f64(value) {
+            let back = time.as_secs_f64();
+            let tolerance = value.abs() * f64::EPSILON * 2.0 + 1e-9;
+            assert!((back - value).abs() <= tolerance, "{} != {}", value, back);
+        } else {
+            assert!(!value.is_finite() || value.abs() >= 9.2e18);
+        }
+    }
+}
+
+proptest::proptest! {
+    #[cfg(all(test, not(miri), feature = "chrono"))]
+    #[test]
+    fn chrono_agrees_with_as_nanos(
+        secs in chrono::DateTime::<chrono::Utc>::MIN_UTC.timestamp()
+            ..=chrono::DateTime::<chrono::Utc>::MAX_UTC.timestamp(),
+        nanos in 0..1_000_000_000_u32,
+    ) {
+        use core::convert::TryFrom;
+
+        use chrono::{DateTime, Utc};
+
+        use crate::UtcTime;
+
+        let value = UtcTime::new(secs, nanos).unwrap();
+        let datetime = DateTime::<Utc>::try_from(value).unwrap();
+        let datetime_nanos =
+            i128::from(datetime.timestamp()) * 1_000_000_000 + i128::from(datetime.timestamp_subsec_nanos());
+        assert_eq!(datetime_nanos, value.as_nanos());
+        assert_eq!(UtcTime::from(datetime), value);
+    }
+
+    #[cfg(all(test, not(miri), feature = "chrono"))]
+    #[test]
+    fn chrono_out_of_range(value: crate::UtcTime) {
+        use core::convert::TryFrom;
+
+        use chrono::{DateTime, Utc};
+
+        use crate::UtcTime;
+
+        let in_range = UtcTime::from(DateTime::<Utc>::MIN_UTC) <= value
+            && value <= UtcTime::from(DateTime::<Utc>::MAX_UTC);
+        assert_eq!(DateTime::<Utc>::try_from(value).is_ok(), in_range);
+    }
+}
+
+proptest::proptest! {
+    #[cfg(all(test, not(miri), feature = "time"))]
+    #[test]
+    fn time_agrees_with_as_nanos(
+        nanos in time::UtcDateTime::MIN.unix_timestamp_nanos()
+            ..=time::UtcDateTime::MAX.unix_timestamp_nanos(),
+    ) {
+        use core::convert::TryFrom;
+
+        use time::{OffsetDateTime, UtcDateTime};
+
+        use crate::UtcTime;
+
+        let value = UtcTime::from_nanos(nanos).unwrap();
+        let utc = UtcDateTime::try_from(value).unwrap();
+        assert_eq!(utc.unix_timestamp_nanos(), value.as_nanos());
+        assert_eq!(UtcTime::from(utc), value);
+
+        let datetime = OffsetDateTime::try_from(value).unwrap();
+        assert_eq!(datetime.unix_timestamp_nanos(), value.as_nanos());
+        assert_eq!(UtcTime::from(datetime), value);
+    }
+
+    #[cfg(all(test, not(miri), feature = "time"))]
+    #[test]
+    fn time_out_of_range(value: crate::UtcTime) {
+        use core::convert::TryFrom;
+
+        use time::{OffsetDateTime, UtcDateTime};
+
+        use crate::UtcTime;
+
+        let in_range =
+            UtcTime::from(UtcDateTime::MIN) <= value && value <= UtcTime::from(UtcDateTime::MAX);
+        assert_eq!(UtcDateTime::try_from(value).is_ok(), in_range);
+        assert_eq!(OffsetDateTime::try_from(value).is_ok(), in_range);
+    }
+}
+
+proptest::proptest! {
+    #[cfg(all(test, not(miri), feature = "jiff"))]
+    #[test]
+    fn jiff_agrees_with_as_nanos(
+        nanos in jiff::Timestamp::MIN.as_nanosecond()..=jiff::Timestamp::MAX.as_nanosecond(),
+    ) {
+        use core::convert::TryFrom;
+
+        use jiff::Timestamp;
+
+        use crate::UtcTime;
+
+        let timestamp = Timestamp::from_nanosecond(nanos).unwrap();
+        let value = UtcTime::from(timestamp);
+        assert_eq!(value.as_nanos(), nanos);
+        assert_eq!(Timestamp::try_from(value), Ok(timestamp));
+    }
+
+    #[cfg(all(test, not(miri), feature = "jiff"))]
+    #[test]
+    fn jiff_out_of_range(value: crate::UtcTime) {
+        use core::convert::TryFrom;
+
+        use jiff::Timestamp;
+
+        use crate::UtcTime;
+
+        let in_range = UtcTime::from(Timestamp::MIN) <= value && value <= UtcTime::from(Timestamp::MAX);
+        assert_eq!(Timestamp::try_from(value).is_ok(), in_range);
+    }
+}
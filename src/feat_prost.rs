@@ -0,0 +1,193 @@
+use core::convert::TryFrom;
+
+use prost_types::Timestamp;
+
+use crate::{ConversionError, Precision, Rfc3339, UtcTime};
+
+/// 0001-01-01T00:00:00Z, the earliest valid `google.protobuf.Timestamp`
+const MIN_SECS: i64 = -62_135_596_800;
+/// 9999-12-31T23:59:59Z, the latest valid `google.protobuf.Timestamp`
+const MAX_SECS: i64 = 253_402_300_799;
+
+impl UtcTime {
+    /// Format the timestamp with the [proto3 JSON mapping](https://protobuf.dev/programming-guides/json/)
+    /// of `google.protobuf.Timestamp`
+    ///
+    /// The result is an RFC 3339 string in UTC with 0, 3, 6 or 9 fractional digits, whichever is
+    /// the shortest lossless representation.
+    ///
+    /// This function is only available with the feature `"prost"`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the year is not in the range 0001 to 9999.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let timestamp = UtcTime::new(1_659_545_693, 895_500_000).unwrap();
+    /// let json = timestamp.to_proto3_json().unwrap();
+    /// assert_eq!(json.to_string(), "2022-08-03T16:54:53.895500Z");
+    /// ```
+    pub fn to_proto3_json(self) -> Result<Rfc3339, ConversionError> {
+        check_range(self)?;
+        let nanos = self.nanos.get();
+        let precision = if nanos == 0 {
+            Precision::Seconds
+        } else if nanos % 1_000_000 == 0 {
+            Precision::Millis
+        } else if nanos % 1_000 == 0 {
+            Precision::Micros
+        } else {
+            Precision::Nanos
+        };
+        Ok(self.rfc3339_with(precision))
+    }
+
+    /// Parse a timestamp in the [proto3 JSON mapping](https://protobuf.dev/programming-guides/json/)
+    /// of `google.protobuf.Timestamp`
+    ///
+    /// Accepts the same input as [`UtcTime::parse_rfc3339()`], including offsets other than `Z`,
+    /// but the year must be in the range 0001 to 9999.
+    ///
+    /// This function is only available with the feature `"prost"`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is malformed, or if the timestamp is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let timestamp = UtcTime::parse_proto3_json("1972-01-01T10:00:20.021-05:00").unwrap();
+    /// assert_eq!(timestamp, UtcTime::new(63_126_020, 21_000_000).unwrap());
+    /// assert!(UtcTime::parse_proto3_json("0000-12-31T23:59:59Z").is_err());
+    /// ```
+    pub fn parse_proto3_json(value: &str) -> Result<Self, ConversionError> {
+        let value = Self::parse_rfc3339(value)?;
+        check_range(value)?;
+        Ok(value)
+    }
+}
+
+fn check_range(value: UtcTime) -> Result<(), ConversionError> {
+    if (MIN_SECS..=MAX_SECS).contains(&value.secs) {
+        Ok(())
+    } else {
+        Err(ConversionError::OVERFLOW)
+    }
+}
+
+/// A [`UtcTime`] outside of the years 0001 to 9999 is converted as is,
+/// even though it is not a valid `google.protobuf.Timestamp`
+impl From<UtcTime> for Timestamp {
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)] // `nanos` is less than 1 000 000 000
+    fn from(value: UtcTime) -> Self {
+        Self {
+            seconds: value.secs,
+            nanos: value.nanos.get() as i32,
+        }
+    }
+}
+
+/// Rejects `nanos` outside of 0 to 999 999 999, and timestamps outside of the years 0001 to 9999
+impl TryFrom<Timestamp> for UtcTime {
+    type Error = ConversionError;
+
+    fn try_from(value: Timestamp) -> Result<Self, Self::Error> {
+        let nanos = u32::try_from(value.nanos).map_err(|_| ConversionError::OUT_OF_RANGE)?;
+        if nanos >= 1_000_000_000 {
+            return Err(ConversionError::OUT_OF_RANGE);
+        }
+        let value = unsafe { Self::new_unchecked(value.seconds, nanos) };
+        check_range(value)?;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use prost_types::Timestamp;
+
+    use crate::{ConversionErrorKind, UtcTime};
+
+    #[test]
+    fn test_timestamp() {
+        let time = UtcTime::new(-1_659_545_693, 895_531_827).unwrap();
+        let timestamp = Timestamp::from(time);
+        assert_eq!(timestamp.seconds, -1_659_545_693);
+        assert_eq!(timestamp.nanos, 895_531_827);
+        assert_eq!(UtcTime::try_from(timestamp), Ok(time));
+
+        for &(seconds, nanos, kind) in &[
+            (0, -1, ConversionErrorKind::OutOfRange),
+            (0, 1_000_000_000, ConversionErrorKind::OutOfRange),
+            (0, i32::MAX, ConversionErrorKind::OutOfRange),
+            (-62_135_596_801, 999_999_999, ConversionErrorKind::Overflow),
+            (253_402_300_800, 0, ConversionErrorKind::Overflow),
+            (i64::MIN, 0, ConversionErrorKind::Overflow),
+        ] {
+            let err = UtcTime::try_from(Timestamp { seconds, nanos }).unwrap_err();
+            assert_eq!(err.kind(), kind, "{} {}", seconds, nanos);
+        }
+
+        let min = UtcTime::try_from(Timestamp {
+            seconds: -62_135_596_800,
+            nanos: 0,
+        })
+        .unwrap();
+        assert_eq!(min.rfc3339().to_string(), "0001-01-01T00:00:00Z");
+        let max = UtcTime::try_from(Timestamp {
+            seconds: 253_402_300_799,
+            nanos: 999_999_999,
+        })
+        .unwrap();
+        assert_eq!(max.rfc3339().to_string(), "9999-12-31T23:59:59.999999999Z");
+
+        // out of spec, but representable
+        let timestamp = Timestamp::from(UtcTime::MAX);
+        assert_eq!(timestamp.seconds, i64::MAX);
+        assert_eq!(timestamp.nanos, 999_999_999);
+    }
+
+    #[test]
+    fn test_proto3_json() {
+        for &(nanos, expected) in &[
+            (0, "2022-08-03T16:54:53Z"),
+            (100_000_000, "2022-08-03T16:54:53.100Z"),
+            (895_000_000, "2022-08-03T16:54:53.895Z"),
+            (895_500_000, "2022-08-03T16:54:53.895500Z"),
+            (895_531_000, "2022-08-03T16:54:53.895531Z"),
+            (895_531_800, "2022-08-03T16:54:53.895531800Z"),
+            (1, "2022-08-03T16:54:53.000000001Z"),
+        ] {
+            let time = UtcTime::new(1_659_545_693, nanos).unwrap();
+            let json = time.to_proto3_json().unwrap().to_string();
+            assert_eq!(json, expected);
+            assert_eq!(UtcTime::parse_proto3_json(&json), Ok(time));
+        }
+
+        let min = UtcTime::parse_proto3_json("0001-01-01T00:00:00Z").unwrap();
+        assert_eq!(min.as_secs(), -62_135_596_800);
+        let max = UtcTime::parse_proto3_json("9999-12-31T23:59:59.999999999Z").unwrap();
+        assert_eq!(max.as_secs(), 253_402_300_799);
+        assert_eq!(
+            max.to_proto3_json().unwrap().to_string(),
+            "9999-12-31T23:59:59.999999999Z",
+        );
+
+        // the offset moves the timestamp out of range
+        let err = UtcTime::parse_proto3_json("0001-01-01T00:00:00+00:01").unwrap_err();
+        assert_eq!(err.kind(), ConversionErrorKind::Overflow);
+        let err = UtcTime::parse_proto3_json("+10000-01-01T00:00:00Z").unwrap_err();
+        assert_eq!(err.kind(), ConversionErrorKind::Overflow);
+        let err = UtcTime::MIN.to_proto3_json().unwrap_err();
+        assert_eq!(err.kind(), ConversionErrorKind::Overflow);
+        assert!(UtcTime::parse_proto3_json("2022-08-03").is_err());
+    }
+}
@@ -5,7 +5,13 @@ use rkyv::{Archive, Archived, Deserialize, Fallible, Resolver, Serialize};
 use crate::UtcTime;
 use crate::u30::U30;
 
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
 /// An archived [`UtcTime`]
+///
+/// An archive produced by [`rkyv::Serialize`] always contains a valid value.
+/// Use `rkyv::check_archived_root()` with the feature `"rkyv-validation"` to access untrusted input,
+/// which rejects values with `nanos` that are not less than 1 000 000 000.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ArchivedUtcTime {
     secs: Archived<i64>,
@@ -46,18 +52,69 @@ impl Archive for UtcTime {
     }
 }
 
+/// # Panics
+///
+/// Panics if the archived `nanos` are not less than 1 000 000 000.
+/// This cannot happen for an archive produced by [`rkyv::Serialize`], or for untrusted input
+/// that was accessed with `rkyv::check_archived_root()`.
+/// `D::Error` cannot be constructed generically in rkyv 0.7, so no error is returned instead.
 impl<D: Fallible + ?Sized> Deserialize<UtcTime, D> for Archived<UtcTime> {
     fn deserialize(&self, deserializer: &mut D) -> Result<UtcTime, D::Error> {
         let secs = Deserialize::<i64, D>::deserialize(&self.secs, deserializer)?;
 
         let nanos: &u32 = unsafe { mem::transmute(&self.nanos) };
         let nanos = Deserialize::<u32, D>::deserialize(nanos, deserializer)?;
+        // Only reachable for unvalidated input. Panic instead of creating an invalid `U30`.
+        assert!(
+            nanos < NANOS_PER_SEC,
+            "invalid ArchivedUtcTime: use `rkyv::check_archived_root()` to access untrusted input",
+        );
         let nanos = unsafe { U30::new_unchecked(nanos) };
 
         Ok(UtcTime { secs, nanos })
     }
 }
 
+#[cfg(feature = "rkyv-validation")]
+impl<C: ?Sized> rkyv::CheckBytes<C> for ArchivedUtcTime
+where
+    Archived<i64>: rkyv::CheckBytes<C>,
+    Archived<u32>: rkyv::CheckBytes<C>,
+{
+    type Error = rkyv::bytecheck::StructCheckError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        use rkyv::CheckBytes;
+        use rkyv::bytecheck::{ErrorBox, StructCheckError};
+
+        let secs = core::ptr::addr_of!((*value).secs);
+        let _: &Archived<i64> =
+            CheckBytes::check_bytes(secs, context).map_err(|err| StructCheckError {
+                field_name: "secs",
+                inner: ErrorBox::new(err),
+            })?;
+
+        let nanos = core::ptr::addr_of!((*value).nanos);
+        let nanos: &Archived<u32> =
+            CheckBytes::check_bytes(nanos, context).map_err(|err| StructCheckError {
+                field_name: "nanos",
+                inner: ErrorBox::new(err),
+            })?;
+        let nanos: &u32 = mem::transmute(nanos);
+        if *nanos >= NANOS_PER_SEC {
+            return Err(StructCheckError {
+                field_name: "nanos",
+                inner: ErrorBox::new(crate::ConversionError::OUT_OF_RANGE),
+            });
+        }
+
+        Ok(&*value)
+    }
+}
+
 impl<S: Fallible + ?Sized> Serialize<S> for UtcTime {
     fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
         let secs = Serialize::<S>::serialize(&self.secs, serializer)?;
@@ -118,3 +175,29 @@ fn test() {
     assert_eq!(&value, &deserialized);
     assert_eq!(archived, &deserialized);
 }
+
+#[cfg(all(test, feature = "rkyv-validation"))]
+#[test]
+fn test_check_bytes() {
+    let value = UtcTime::new(1_661_209_811, 999_999_999).unwrap();
+    let mut bytes = rkyv::to_bytes::<_, 32>(&value).unwrap();
+    assert_eq!(bytes.len(), 16);
+
+    let archived = rkyv::check_archived_root::<UtcTime>(&bytes).unwrap();
+    assert_eq!(&value, archived);
+    let deserialized: UtcTime = archived.deserialize(&mut rkyv::Infallible).unwrap();
+    assert_eq!(value, deserialized);
+
+    // `nanos` is stored at offset 8
+    for &nanos in &[1_000_000_000_u32, u32::MAX] {
+        bytes[8..12].copy_from_slice(&rkyv::to_bytes::<_, 4>(&nanos).unwrap());
+        let err = rkyv::check_archived_root::<UtcTime>(&bytes).unwrap_err();
+        assert!(err.to_string().contains("nanos"), "{}", err);
+    }
+
+    bytes[8..12].copy_from_slice(&rkyv::to_bytes::<_, 4>(&0_u32).unwrap());
+    let archived = rkyv::check_archived_root::<UtcTime>(&bytes).unwrap();
+    assert_eq!(archived, &UtcTime::new(1_661_209_811, 0).unwrap());
+
+    assert!(rkyv::check_archived_root::<UtcTime>(&bytes[..12]).is_err());
+}
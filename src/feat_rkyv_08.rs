@@ -0,0 +1,201 @@
+use core::{cmp, error, fmt};
+
+use rkyv_08::bytecheck::CheckBytes;
+use rkyv_08::rancor::{Fallible, Source};
+use rkyv_08::rend::{i64_le, u32_le};
+use rkyv_08::{Archive, Deserialize, Place, Portable, Serialize};
+
+use crate::UtcTime;
+use crate::u30::U30;
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// An archived [`UtcTime`] for rkyv 0.8
+///
+/// The fields are stored in little-endian byte order, independent of the features of `rkyv`,
+/// so the layout is the same on every platform.
+/// `rkyv::access()` rejects values with `nanos` that are not less than 1 000 000 000.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct ArchivedUtcTime {
+    secs: i64_le,
+    nanos: u32_le,
+}
+
+// SAFETY: `ArchivedUtcTime` is `repr(C)`, and its fields are `Portable`.
+unsafe impl Portable for ArchivedUtcTime {}
+
+impl ArchivedUtcTime {
+    /// Write the fields individually, so the padding bytes are not written
+    unsafe fn emplace(secs: i64, nanos: u32, out: *mut Self) {
+        core::ptr::addr_of_mut!((*out).secs).write(i64_le::from_native(secs));
+        core::ptr::addr_of_mut!((*out).nanos).write(u32_le::from_native(nanos));
+    }
+}
+
+impl Archive for UtcTime {
+    type Archived = ArchivedUtcTime;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+        unsafe { ArchivedUtcTime::emplace(self.secs, self.nanos.get(), out.ptr()) };
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for UtcTime {
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+/// # Panics
+///
+/// Panics if the archived `nanos` are not less than 1 000 000 000.
+/// This cannot happen for an archive produced by [`rkyv::Serialize`](Serialize), or for input
+/// that was accessed with `rkyv::access()`.
+/// No error is returned instead, because `D::Error` is not required to implement `rancor::Source`,
+/// e.g. for `rancor::Infallible`.
+impl<D: Fallible + ?Sized> Deserialize<UtcTime, D> for ArchivedUtcTime {
+    fn deserialize(&self, _: &mut D) -> Result<UtcTime, D::Error> {
+        let nanos = self.nanos.to_native();
+        // Only reachable for unvalidated input. Panic instead of creating an invalid `U30`.
+        assert!(
+            nanos < NANOS_PER_SEC,
+            "invalid ArchivedUtcTime: use `rkyv::access()` to access untrusted input",
+        );
+        let nanos = unsafe { U30::new_unchecked(nanos) };
+        Ok(UtcTime {
+            secs: self.secs.to_native(),
+            nanos,
+        })
+    }
+}
+
+/// The archived `nanos` are not less than 1 000 000 000
+#[derive(Debug)]
+struct NanosError(u32);
+
+impl fmt::Display for NanosError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`nanos` field of `UtcTime` is not less than 1 billion: {}",
+            self.0,
+        )
+    }
+}
+
+impl error::Error for NanosError {}
+
+// SAFETY: every bit pattern is a valid `i64_le` and `u32_le`, and the range of `nanos` is checked.
+unsafe impl<C> CheckBytes<C> for ArchivedUtcTime
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        let nanos = (*value).nanos.to_native();
+        if nanos >= NANOS_PER_SEC {
+            return Err(C::Error::new(NanosError(nanos)));
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq<UtcTime> for ArchivedUtcTime {
+    fn eq(&self, other: &UtcTime) -> bool {
+        self.secs == other.secs && self.nanos == other.nanos.get()
+    }
+}
+
+impl PartialEq<ArchivedUtcTime> for UtcTime {
+    fn eq(&self, other: &ArchivedUtcTime) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<UtcTime> for ArchivedUtcTime {
+    fn partial_cmp(&self, other: &UtcTime) -> Option<cmp::Ordering> {
+        let this = (self.secs.to_native(), self.nanos.to_native());
+        this.partial_cmp(&(other.secs, other.nanos.get()))
+    }
+}
+
+impl PartialOrd<ArchivedUtcTime> for UtcTime {
+    fn partial_cmp(&self, other: &ArchivedUtcTime) -> Option<cmp::Ordering> {
+        other.partial_cmp(self).map(cmp::Ordering::reverse)
+    }
+}
+
+impl fmt::Display for ArchivedUtcTime {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{:09}", self.secs, self.nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::MaybeUninit;
+
+    use rkyv_08::api::low::{access, deserialize, to_bytes_in_with_alloc};
+    use rkyv_08::rancor::Failure;
+    use rkyv_08::ser::allocator::SubAllocator;
+    use rkyv_08::ser::writer::Buffer;
+    use rkyv_08::util::Align;
+
+    use super::ArchivedUtcTime;
+    use crate::UtcTime;
+
+    #[test]
+    fn test_round_trip() {
+        let value = UtcTime::new(-1_661_209_811, 467_621_425).unwrap();
+
+        let mut output = Align([MaybeUninit::<u8>::uninit(); 32]);
+        let mut alloc = [MaybeUninit::<u8>::uninit(); 32];
+        let bytes = to_bytes_in_with_alloc::<_, _, Failure>(
+            &value,
+            Buffer::from(&mut *output),
+            SubAllocator::new(&mut alloc),
+        )
+        .unwrap();
+
+        // little-endian on every platform, with zeroed padding
+        let mut expected = [0; 16];
+        expected[..8].copy_from_slice(&(-1_661_209_811_i64).to_le_bytes());
+        expected[8..12].copy_from_slice(&467_621_425_u32.to_le_bytes());
+        assert_eq!(&*bytes, &expected);
+
+        let archived = access::<ArchivedUtcTime, Failure>(&bytes).unwrap();
+        let deserialized: UtcTime = deserialize::<_, Failure>(archived).unwrap();
+        assert_eq!(&value, archived);
+        assert_eq!(value, deserialized);
+        assert!(archived < &UtcTime::EPOCH);
+        assert!(UtcTime::MAX > *archived);
+        assert_eq!(archived.to_string(), value.to_string());
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut bytes = Align([0_u8; 16]);
+        bytes[..8].copy_from_slice(&1_661_209_811_i64.to_le_bytes());
+
+        for &(nanos, valid) in &[
+            (0, true),
+            (999_999_999, true),
+            (1_000_000_000, false),
+            (u32::MAX, false),
+        ] {
+            bytes[8..12].copy_from_slice(&u32::to_le_bytes(nanos));
+            let archived = access::<ArchivedUtcTime, Failure>(&*bytes);
+            assert_eq!(archived.is_ok(), valid, "{}", nanos);
+            if let Ok(archived) = archived {
+                assert_eq!(archived, &UtcTime::new(1_661_209_811, nanos).unwrap());
+            }
+        }
+
+        assert!(access::<ArchivedUtcTime, Failure>(&bytes[..12]).is_err());
+    }
+}
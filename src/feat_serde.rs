@@ -8,6 +8,12 @@ impl<'de> Deserialize<'de> for UtcTime {
     where
         D: Deserializer<'de>,
     {
+        // Accept the tuple, too, so data written without the feature stays readable.
+        #[cfg(feature = "serde-human-readable")]
+        if deserializer.is_human_readable() {
+            return crate::serde::flexible::deserialize(deserializer);
+        }
+
         let (secs, nanos) = <(i64, u32)>::deserialize(deserializer)?;
         Self::new(secs, nanos).ok_or_else(|| D::Error::custom("UtcTime out of range"))
     }
@@ -18,12 +24,18 @@ impl Serialize for UtcTime {
     where
         S: serde::Serializer,
     {
+        #[cfg(feature = "serde-human-readable")]
+        if serializer.is_human_readable() {
+            return crate::serde::rfc3339::serialize(self, serializer);
+        }
+
         let Self { secs, nanos } = *self;
         (secs, nanos.get()).serialize(serializer)
     }
 }
 
 #[cfg(test)]
+#[cfg(not(feature = "serde-human-readable"))]
 #[test]
 fn minimal_test() {
     // serialize
@@ -49,3 +61,17 @@ fn minimal_test() {
     // deserialize illegal nanos
     assert!(serde_json::from_str::<UtcTime>("[1659539413,8854573940]").is_err());
 }
+
+#[cfg(test)]
+#[cfg(feature = "serde-human-readable")]
+#[test]
+fn human_readable_test() {
+    let value = UtcTime::new(1_659_539_413, 885_457_394).unwrap();
+    let string = serde_json::to_string(&value).unwrap();
+    assert_eq!(string, r#""2022-08-03T15:10:13.885457394Z""#);
+    let actual: UtcTime = serde_json::from_str(&string).unwrap();
+    assert_eq!(actual, value);
+
+    let actual: UtcTime = serde_json::from_str("[1659539413,885457394]").unwrap();
+    assert_eq!(actual, value);
+}
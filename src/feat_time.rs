@@ -0,0 +1,82 @@
+use core::convert::TryFrom;
+
+use time::{OffsetDateTime, UtcDateTime};
+
+use crate::{ConversionError, UtcTime};
+
+impl From<OffsetDateTime> for UtcTime {
+    #[inline]
+    fn from(value: OffsetDateTime) -> Self {
+        // UTC offsets are whole seconds, so the nanoseconds are the same in every offset
+        unsafe { Self::new_unchecked(value.unix_timestamp(), value.nanosecond()) }
+    }
+}
+
+impl From<UtcDateTime> for UtcTime {
+    #[inline]
+    fn from(value: UtcDateTime) -> Self {
+        unsafe { Self::new_unchecked(value.unix_timestamp(), value.nanosecond()) }
+    }
+}
+
+/// Fails if the time is outside of the range of [`OffsetDateTime`],
+/// i.e. the years -9999 to 9999, or ±999 999 with the feature `"large-dates"` of `time`
+impl TryFrom<UtcTime> for OffsetDateTime {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: UtcTime) -> Result<Self, ConversionError> {
+        Self::from_unix_timestamp_nanos(value.as_nanos()).map_err(|_| ConversionError::OVERFLOW)
+    }
+}
+
+/// Fails if the time is outside of the range of [`UtcDateTime`],
+/// i.e. the years -9999 to 9999, or ±999 999 with the feature `"large-dates"` of `time`
+impl TryFrom<UtcTime> for UtcDateTime {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: UtcTime) -> Result<Self, ConversionError> {
+        Self::from_unix_timestamp_nanos(value.as_nanos()).map_err(|_| ConversionError::OVERFLOW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use time::{OffsetDateTime, UtcDateTime, UtcOffset};
+
+    use crate::{ConversionErrorKind, UtcTime};
+
+    #[test]
+    fn test_time() {
+        let time = UtcTime::new(-1_659_545_693, 895_531_827).unwrap();
+        let datetime = OffsetDateTime::try_from(time).unwrap();
+        assert_eq!(datetime.unix_timestamp_nanos(), time.as_nanos());
+        assert_eq!(UtcTime::from(datetime), time);
+
+        let offset = datetime.to_offset(UtcOffset::from_hms(-5, -30, 0).unwrap());
+        assert_eq!(UtcTime::from(offset), time);
+
+        let utc = UtcDateTime::try_from(time).unwrap();
+        assert_eq!(utc.unix_timestamp_nanos(), time.as_nanos());
+        assert_eq!(UtcTime::from(utc), time);
+
+        let min = UtcTime::from(UtcDateTime::MIN);
+        let max = UtcTime::from(UtcDateTime::MAX);
+        assert_eq!(UtcDateTime::try_from(min), Ok(UtcDateTime::MIN));
+        assert_eq!(UtcDateTime::try_from(max), Ok(UtcDateTime::MAX));
+        for &time in &[
+            UtcTime::new(min.as_secs() - 1, 999_999_999).unwrap(),
+            UtcTime::new(max.as_secs() + 1, 0).unwrap(),
+            UtcTime::MIN,
+            UtcTime::MAX,
+        ] {
+            let err = UtcDateTime::try_from(time).unwrap_err();
+            assert_eq!(err.kind(), ConversionErrorKind::Overflow);
+            let err = OffsetDateTime::try_from(time).unwrap_err();
+            assert_eq!(err.kind(), ConversionErrorKind::Overflow);
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::u30::U30;
+use crate::{TaiTime, UtcTime};
+
+const SECS_PER_WEEK: u32 = 7 * 24 * 60 * 60;
+
+/// Start of the GPS week 0 in [`TaiTime`] seconds: 1980-01-06T00:00:00 UTC, TAI − 19 s
+const GPS_EPOCH: i64 = 315_964_800 + 19;
+/// Start of the Galileo week 0: 1999-08-22T00:00:00 GST, i.e. GPS week 1024
+const GALILEO_EPOCH: i64 = GPS_EPOCH + 1024 * SECS_PER_WEEK as i64;
+/// Start of the BeiDou week 0: 2006-01-01T00:00:00 UTC, TAI − 33 s
+const BEIDOU_EPOCH: i64 = 1_136_073_600 + 33;
+
+#[allow(clippy::cast_possible_truncation)] // `tow` is less than one week
+fn split_tai(time: TaiTime, epoch: i64) -> Option<(u32, u32, U30)> {
+    let secs = u64::try_from(time.as_secs().checked_sub(epoch)?).ok()?;
+    let week = u32::try_from(secs / u64::from(SECS_PER_WEEK)).ok()?;
+    let tow = (secs % u64::from(SECS_PER_WEEK)) as u32;
+    Some((week, tow, unsafe {
+        U30::new_unchecked(time.subsec_nanos())
+    }))
+}
+
+fn join_tai(week: u32, tow: u32, nanos: U30, epoch: i64) -> Option<TaiTime> {
+    let secs = i64::from(week) * i64::from(SECS_PER_WEEK) + i64::from(tow);
+    let secs = secs.checked_add(epoch)?;
+    Some(unsafe { TaiTime::new_unchecked(secs, nanos.get()) })
+}
+
+/// Find the full week number closest to `reference`, if only the lowest `bits` bits of the week are known
+fn unroll_week(week: u32, bits: u32, tow: u32, reference: TaiTime, epoch: i64) -> Option<u32> {
+    if bits == 0 || bits >= 32 || week >> bits != 0 {
+        return None;
+    }
+    let period = i128::from(SECS_PER_WEEK) << bits;
+    let secs = i128::from(week) * i128::from(SECS_PER_WEEK) + i128::from(tow);
+    let wanted = i128::from(reference.as_secs()) - i128::from(epoch);
+    // candidates before the epoch do not exist, so the closest one is the first one
+    let rollovers = (wanted - secs + period / 2).div_euclid(period).max(0);
+    let rollovers = u32::try_from(rollovers).ok()?;
+    rollovers.checked_mul(1 << bits)?.checked_add(week)
+}
+
+macro_rules! gnss_time {
+    (
+        $(#[$meta:meta])*
+        $Name:ident, $epoch:ident, $example_week:literal, $example_tow:literal
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $Name {
+            week: u32,
+            tow: u32,
+            nanos: U30,
+        }
+
+        impl $Name {
+            /// Create a new timestamp from the week number and the time of week
+            ///
+            /// Returns `None` if `tow_secs` is not less than 604800 (one week),
+            /// or if `nanos` is not less than 1 000 000 000.
+            #[must_use]
+            #[const_fn::const_fn("1.56")]
+            pub fn new(week: u32, tow_secs: u32, nanos: u32) -> Option<Self> {
+                if tow_secs >= SECS_PER_WEEK || nanos >= 1_000_000_000 {
+                    return None;
+                }
+                Some(Self {
+                    week,
+                    tow: tow_secs,
+                    nanos: unsafe { U30::new_unchecked(nanos) },
+                })
+            }
+
+            /// Create a new timestamp if only the lowest `bits` bits of the week number are known
+            ///
+            /// Receivers often transmit the week number modulo 2<sup>`bits`</sup>, e.g. modulo 1024
+            /// for a 10-bit week counter, so the week number "rolls over" every few years.
+            /// The returned timestamp is the candidate closest to `reference`,
+            /// which must be less than half a rollover period (about 9.8 years for 10 bits) off.
+            ///
+            /// Returns `None` if `week` does not fit into `bits` bits, if `bits` is not in `1..32`,
+            /// if `tow_secs` or `nanos` are out of range, or if the result would be out of range.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use utcnow::{", stringify!($Name), ", UtcTime};")]
+            /// let reference = UtcTime::parse_rfc3339("2016-06-01T00:00:00Z").unwrap();
+            #[doc = concat!(
+                "let time = ", stringify!($Name), "::from_truncated_week(",
+                $example_week, " % 1024, 10, ", $example_tow, ", 0, reference).unwrap();",
+            )]
+            #[doc = concat!("assert_eq!(time.week(), ", $example_week, ");")]
+            /// ```
+            #[must_use]
+            pub fn from_truncated_week(
+                week: u32,
+                bits: u32,
+                tow_secs: u32,
+                nanos: u32,
+                reference: UtcTime,
+            ) -> Option<Self> {
+                let time = Self::new(week, tow_secs, nanos)?;
+                let week = unroll_week(week, bits, tow_secs, reference.to_tai()?, $epoch)?;
+                Some(Self { week, ..time })
+            }
+
+            /// The week number
+            #[must_use]
+            #[inline]
+            pub const fn week(self) -> u32 {
+                self.week
+            }
+
+            /// Whole seconds since the start of the [week](Self::week), in the range `0..604800`
+            #[must_use]
+            #[inline]
+            pub const fn tow_secs(self) -> u32 {
+                self.tow
+            }
+
+            /// Fractional number of nanoseconds
+            #[must_use]
+            #[inline]
+            #[const_fn::const_fn("1.56")]
+            pub fn subsec_nanos(self) -> u32 {
+                self.nanos.get()
+            }
+
+            /// Convert a [`UtcTime`], using the [installed](crate::LeapSecondTable::install) leap second table
+            ///
+            /// Returns `None` if the time is before the epoch of the time scale, or if it is out of range.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use utcnow::{", stringify!($Name), ", UtcTime};")]
+            /// let utc = UtcTime::parse_rfc3339("2017-01-01T00:00:00Z").unwrap();
+            #[doc = concat!("let time = ", stringify!($Name), "::from_utc(utc).unwrap();")]
+            #[doc = concat!("assert_eq!(time.to_string(), \"", $example_week, ":", $example_tow, ".000000000\");")]
+            /// assert_eq!(time.to_utc(), Some(utc));
+            /// ```
+            #[must_use]
+            pub fn from_utc(time: UtcTime) -> Option<Self> {
+                Self::from_tai(time.to_tai()?)
+            }
+
+            /// Convert to [`UtcTime`], using the [installed](crate::LeapSecondTable::install) leap second table
+            ///
+            /// A timestamp in a positive leap second is folded into the next second,
+            /// see [`TaiTime::to_utc()`].
+            #[must_use]
+            pub fn to_utc(self) -> Option<UtcTime> {
+                self.to_tai()?.to_utc()
+            }
+
+            /// Convert a [`TaiTime`]
+            ///
+            /// Returns `None` if the time is before the epoch of the time scale, or if it is out of range.
+            #[must_use]
+            pub fn from_tai(time: TaiTime) -> Option<Self> {
+                let (week, tow, nanos) = split_tai(time, $epoch)?;
+                Some(Self { week, tow, nanos })
+            }
+
+            /// Convert to [`TaiTime`]
+            #[must_use]
+            pub fn to_tai(self) -> Option<TaiTime> {
+                join_tai(self.week, self.tow, self.nanos, $epoch)
+            }
+        }
+
+        impl fmt::Display for $Name {
+            /// Formats the value as `week:tow`, e.g. `1930:18.000000000`
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}:{}.{:09}", self.week, self.tow, self.nanos)
+            }
+        }
+    };
+}
+
+gnss_time! {
+    /// GPS time as week number and time of week
+    ///
+    /// Week 0 started on 1980-01-06T00:00:00 UTC. GPS time does not have leap seconds,
+    /// and is always 19 seconds behind [TAI](TaiTime).
+    GpsTime, GPS_EPOCH, "1930", "18"
+}
+
+gnss_time! {
+    /// Galileo System Time (GST) as week number and time of week
+    ///
+    /// Week 0 started on 1999-08-22T00:00:00 GST, 13 seconds before midnight UTC.
+    /// Just like [`GpsTime`], GST is always 19 seconds behind [TAI](TaiTime),
+    /// but the week numbers are 1024 less.
+    GalileoTime, GALILEO_EPOCH, "906", "18"
+}
+
+gnss_time! {
+    /// BeiDou Time (BDT) as week number and time of week
+    ///
+    /// Week 0 started on 2006-01-01T00:00:00 UTC. BDT does not have leap seconds,
+    /// and is always 33 seconds behind [TAI](TaiTime).
+    BeiDouTime, BEIDOU_EPOCH, "574", "4"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BeiDouTime, GalileoTime, GpsTime, TaiTime, UtcTime};
+
+    fn utc(s: &str) -> UtcTime {
+        UtcTime::parse_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn test_epochs() {
+        let gps = GpsTime::new(0, 0, 0).unwrap();
+        assert_eq!(gps.to_utc(), Some(utc("1980-01-06T00:00:00Z")));
+        let galileo = GalileoTime::new(0, 0, 0).unwrap();
+        assert_eq!(galileo.to_utc(), Some(utc("1999-08-21T23:59:47Z")));
+        let beidou = BeiDouTime::new(0, 0, 0).unwrap();
+        assert_eq!(beidou.to_utc(), Some(utc("2006-01-01T00:00:00Z")));
+
+        assert_eq!(GpsTime::from_utc(utc("1980-01-05T23:59:59.9Z")), None);
+        assert_eq!(BeiDouTime::from_utc(utc("2005-12-31T23:59:59Z")), None);
+    }
+
+    #[test]
+    fn test_same_instant() {
+        let time = utc("2024-02-29T12:34:56.789Z");
+        let gps = GpsTime::from_utc(time).unwrap();
+        let galileo = GalileoTime::from_utc(time).unwrap();
+        let beidou = BeiDouTime::from_utc(time).unwrap();
+        assert_eq!(galileo.week() + 1024, gps.week());
+        assert_eq!(galileo.tow_secs(), gps.tow_secs());
+        assert_eq!(beidou.week() + 1356, gps.week());
+        assert_eq!(beidou.tow_secs() + 14, gps.tow_secs());
+        assert_eq!(gps.subsec_nanos(), 789_000_000);
+        assert_eq!(gps.to_string(), "2303:390914.789000000");
+        for &result in &[gps.to_utc(), galileo.to_utc(), beidou.to_utc()] {
+            assert_eq!(result, Some(time));
+        }
+    }
+
+    #[test]
+    fn test_leap_second() {
+        let before = GpsTime::from_utc(utc("2016-12-31T23:59:59Z")).unwrap();
+        let after = GpsTime::from_utc(utc("2017-01-01T00:00:00Z")).unwrap();
+        assert_eq!((before.week(), before.tow_secs()), (1930, 16));
+        assert_eq!((after.week(), after.tow_secs()), (1930, 18));
+
+        let leap = GpsTime::new(1930, 17, 0).unwrap();
+        assert!(leap.to_tai().unwrap().is_leap_second());
+        assert_eq!(leap.to_utc(), after.to_utc());
+    }
+
+    #[test]
+    fn test_truncated_week() {
+        let reference = utc("2024-01-01T00:00:00Z");
+        let time = GpsTime::from_truncated_week(2290 % 1024, 10, 0, 0, reference).unwrap();
+        assert_eq!(time.week(), 2290);
+
+        // the closest candidate may be in the previous or in the next rollover period
+        let time = GpsTime::from_truncated_week(1023, 10, 0, 0, utc("1999-08-22T00:00:00Z"));
+        assert_eq!(time.unwrap().week(), 1023);
+        let time = GpsTime::from_truncated_week(0, 10, 0, 0, utc("1999-08-21T00:00:00Z"));
+        assert_eq!(time.unwrap().week(), 1024);
+        let time = GpsTime::from_truncated_week(1, 10, 5, 0, UtcTime::EPOCH);
+        assert_eq!(time.unwrap().week(), 1);
+
+        assert_eq!(
+            GpsTime::from_truncated_week(1024, 10, 0, 0, reference),
+            None
+        );
+        assert_eq!(GpsTime::from_truncated_week(0, 0, 0, 0, reference), None);
+        assert_eq!(
+            GpsTime::from_truncated_week(0, 10, 604_800, 0, reference),
+            None
+        );
+        assert_eq!(
+            GpsTime::from_truncated_week(0, 10, 0, 0, UtcTime::MAX),
+            None
+        );
+
+        let reference = utc("2030-01-01T00:00:00Z");
+        let time = BeiDouTime::from_truncated_week(1250 % 1024, 10, 0, 0, reference).unwrap();
+        assert_eq!(time.week(), 1250);
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_eq!(GpsTime::new(0, 604_800, 0), None);
+        assert_eq!(GpsTime::new(0, 0, 1_000_000_000), None);
+        let max = GpsTime::new(u32::MAX, 604_799, 999_999_999).unwrap();
+        assert_eq!(GpsTime::from_tai(max.to_tai().unwrap()), Some(max));
+        assert_eq!(GpsTime::from_tai(TaiTime::new(i64::MAX, 0).unwrap()), None);
+        assert_eq!(GpsTime::from_tai(TaiTime::new(i64::MIN, 0).unwrap()), None);
+    }
+}
@@ -1,37 +1,117 @@
 use core::fmt;
+use core::time::Duration;
 
-use crate::{Error, Result, UtcTime};
+use crate::{Backend, ClockError, ClockSource, ErrorKind, RawClockReading, Result, UtcTime};
 
 pub(crate) const IMPLEMENTED: bool = true;
 pub(crate) const INFALLIBLE: bool = false;
 
+pub(crate) const BACKEND: Backend = Backend::Libc;
+pub(crate) const CLOCK_ID: &str = "CLOCK_REALTIME";
+
 #[allow(trivial_casts)]
-#[allow(clippy::cast_lossless)]
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_sign_loss)]
 #[allow(clippy::unnecessary_cast)]
+pub(crate) fn resolution() -> Option<Duration> {
+    let mut res = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    match unsafe { libc::clock_getres(libc::CLOCK_REALTIME, &mut res) } {
+        0 => Some(Duration::new(res.tv_sec as u64, res.tv_nsec as u32)),
+        _ => None,
+    }
+}
+
 pub(crate) fn utcnow() -> Result<UtcTime> {
+    let (secs, nanos) = clock_gettime(libc::CLOCK_REALTIME)?;
+    Ok(unsafe { UtcTime::new_unchecked(secs, nanos) })
+}
+
+pub(crate) const fn clock_implemented(source: ClockSource) -> bool {
+    match source {
+        ClockSource::Realtime | ClockSource::Monotonic => true,
+        ClockSource::RealtimeCoarse | ClockSource::Tai | ClockSource::Boottime => {
+            cfg!(any(target_os = "android", target_os = "fuchsia"))
+        },
+    }
+}
+
+pub(crate) const fn clock_infallible(_: ClockSource) -> bool {
+    false
+}
+
+pub(crate) fn now_from(source: ClockSource) -> core::result::Result<RawClockReading, ClockError> {
+    let clock_id = match source {
+        ClockSource::Realtime => libc::CLOCK_REALTIME,
+        ClockSource::Monotonic => libc::CLOCK_MONOTONIC,
+        #[cfg(any(target_os = "android", target_os = "fuchsia"))]
+        ClockSource::RealtimeCoarse => libc::CLOCK_REALTIME_COARSE,
+        #[cfg(any(target_os = "android", target_os = "fuchsia"))]
+        ClockSource::Tai => libc::CLOCK_TAI,
+        #[cfg(any(target_os = "android", target_os = "fuchsia"))]
+        ClockSource::Boottime => libc::CLOCK_BOOTTIME,
+        #[allow(unreachable_patterns)]
+        _ => return Err(ClockError::unsupported(source)),
+    };
+    match clock_gettime(clock_id) {
+        Ok((secs, nanos)) => Ok(unsafe { RawClockReading::new_unchecked(source, secs, nanos) }),
+        Err(err) => Err(ClockError::new(source, err.kind(), err.raw_os_error())),
+    }
+}
+
+#[allow(trivial_casts)]
+#[allow(clippy::cast_lossless)]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::unnecessary_cast)]
+fn clock_gettime(clock_id: libc::clockid_t) -> core::result::Result<(i64, u32), OsError> {
     let mut now = libc::timespec {
         tv_sec: 0,
         tv_nsec: 0,
     };
-    let result = unsafe { libc::clock_gettime(libc::CLOCK_REALTIME, &mut now) };
+    let result = unsafe { libc::clock_gettime(clock_id, &mut now) };
     if result != 0 {
         #[cfg(not(any(target_os = "emscripten", target_os = "haiku")))]
-        return Err(Error(OsError(errno::errno().0)));
+        return Err(OsError(errno::errno().0));
 
         #[cfg(any(target_os = "emscripten", target_os = "haiku"))]
-        return Err(Error(OsError()));
+        return Err(OsError());
     }
 
     let secs = now.tv_sec as i64; // tv_sec is i32 in emscripten
     let nanos = now.tv_nsec as u32;
-    Ok(unsafe { UtcTime::new_unchecked(secs, nanos) })
+    Ok((secs, nanos))
 }
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct OsError(#[cfg(not(any(target_os = "emscripten", target_os = "haiku")))] i32);
 
+impl OsError {
+    #[cfg(not(any(target_os = "emscripten", target_os = "haiku")))]
+    #[allow(clippy::unnecessary_wraps)]
+    #[inline]
+    pub(crate) fn raw_os_error(self) -> Option<i32> {
+        Some(self.0)
+    }
+
+    #[cfg(any(target_os = "emscripten", target_os = "haiku"))]
+    #[inline]
+    pub(crate) fn raw_os_error(self) -> Option<i32> {
+        None
+    }
+
+    pub(crate) fn kind(self) -> ErrorKind {
+        match self.raw_os_error() {
+            Some(libc::ENOSYS) | Some(libc::EOPNOTSUPP) => ErrorKind::Unsupported,
+            Some(libc::EPERM) | Some(libc::EACCES) => ErrorKind::PermissionDenied,
+            Some(libc::EINVAL) => ErrorKind::InvalidClock,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
 #[allow(trivial_casts)] // msg is already `*mut u8` on thumbv7neon-linux-androideabi
 impl fmt::Display for OsError {
     #[cfg(not(any(target_os = "emscripten", target_os = "haiku")))]
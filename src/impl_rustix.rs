@@ -1,13 +1,35 @@
 use core::convert::Infallible;
 use core::fmt;
+use core::time::Duration;
 
-use rustix::time::{ClockId, clock_gettime};
+use rustix::time::{ClockId, Timespec, clock_gettime};
+#[cfg(target_os = "linux")]
+use rustix::time::{DynamicClockId, clock_gettime_dynamic};
 
-use crate::{Result, UtcTime};
+use crate::{Backend, ClockError, ClockSource, ErrorKind, RawClockReading, Result, UtcTime};
 
 pub(crate) const IMPLEMENTED: bool = true;
 pub(crate) const INFALLIBLE: bool = true;
 
+pub(crate) const BACKEND: Backend = Backend::Rustix;
+pub(crate) const CLOCK_ID: &str = "CLOCK_REALTIME";
+
+#[cfg(not(target_os = "redox"))]
+#[allow(trivial_casts)]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::unnecessary_cast)]
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn resolution() -> Option<Duration> {
+    let res = rustix::time::clock_getres(ClockId::Realtime);
+    Some(Duration::new(res.tv_sec as u64, res.tv_nsec as u32))
+}
+
+#[cfg(target_os = "redox")]
+pub(crate) fn resolution() -> Option<Duration> {
+    None
+}
+
 #[allow(trivial_casts)]
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_sign_loss)]
@@ -20,9 +42,82 @@ pub(crate) fn utcnow() -> Result<UtcTime> {
     Ok(unsafe { UtcTime::new_unchecked(secs, nanos) })
 }
 
+pub(crate) const fn clock_implemented(source: ClockSource) -> bool {
+    match source {
+        ClockSource::Realtime | ClockSource::Monotonic => true,
+        ClockSource::RealtimeCoarse | ClockSource::Tai | ClockSource::Boottime => {
+            cfg!(target_os = "linux")
+        },
+    }
+}
+
+pub(crate) const fn clock_infallible(source: ClockSource) -> bool {
+    match source {
+        ClockSource::Realtime | ClockSource::Monotonic => true,
+        ClockSource::RealtimeCoarse => cfg!(target_os = "linux"),
+        // CLOCK_TAI and CLOCK_BOOTTIME were added in Linux 2.6.39 and 3.10
+        ClockSource::Tai | ClockSource::Boottime => false,
+    }
+}
+
+pub(crate) fn now_from(source: ClockSource) -> core::result::Result<RawClockReading, ClockError> {
+    let now = match source {
+        ClockSource::Realtime => clock_gettime(ClockId::Realtime),
+        ClockSource::Monotonic => clock_gettime(ClockId::Monotonic),
+        #[cfg(target_os = "linux")]
+        ClockSource::RealtimeCoarse => clock_gettime(ClockId::RealtimeCoarse),
+        #[cfg(target_os = "linux")]
+        ClockSource::Tai => {
+            clock_gettime_dynamic(DynamicClockId::Tai).map_err(|err| os_error(source, err))?
+        },
+        #[cfg(target_os = "linux")]
+        ClockSource::Boottime => {
+            clock_gettime_dynamic(DynamicClockId::Boottime).map_err(|err| os_error(source, err))?
+        },
+        #[allow(unreachable_patterns)]
+        _ => return Err(ClockError::unsupported(source)),
+    };
+    Ok(reading(source, now))
+}
+
+#[allow(trivial_casts)]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::unnecessary_cast)]
+fn reading(source: ClockSource, now: Timespec) -> RawClockReading {
+    let secs = now.tv_sec as i64;
+    let nanos = now.tv_nsec as u32;
+    unsafe { RawClockReading::new_unchecked(source, secs, nanos) }
+}
+
+#[cfg(target_os = "linux")]
+fn os_error(source: ClockSource, err: rustix::io::Errno) -> ClockError {
+    use rustix::io::Errno;
+
+    let kind = match err {
+        Errno::NOSYS | Errno::OPNOTSUPP => ErrorKind::Unsupported,
+        Errno::PERM | Errno::ACCESS => ErrorKind::PermissionDenied,
+        Errno::INVAL => ErrorKind::InvalidClock,
+        _ => ErrorKind::Other,
+    };
+    ClockError::new(source, kind, Some(err.raw_os_error()))
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct OsError(Infallible);
 
+impl OsError {
+    #[inline]
+    pub(crate) fn raw_os_error(self) -> Option<i32> {
+        match self.0 {}
+    }
+
+    #[inline]
+    pub(crate) fn kind(self) -> ErrorKind {
+        match self.0 {}
+    }
+}
+
 impl fmt::Display for OsError {
     #[inline]
     fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1,24 +1,84 @@
 use core::fmt;
+use core::time::Duration;
 
-use wasi::{CLOCKID_REALTIME, Errno, clock_time_get};
+use wasi::{
+    CLOCKID_MONOTONIC, CLOCKID_REALTIME, Clockid, ERRNO_ACCES, ERRNO_INVAL, ERRNO_NOSYS,
+    ERRNO_NOTCAPABLE, ERRNO_NOTSUP, ERRNO_PERM, Errno, clock_res_get, clock_time_get,
+};
 
-use crate::{Result, UtcTime};
+use crate::{Backend, ClockError, ClockSource, ErrorKind, RawClockReading, Result, UtcTime};
 
 pub(crate) const IMPLEMENTED: bool = true;
 pub(crate) const INFALLIBLE: bool = false;
 
+pub(crate) const BACKEND: Backend = Backend::Wasi;
+pub(crate) const CLOCK_ID: &str = "CLOCKID_REALTIME";
+
+pub(crate) fn resolution() -> Option<Duration> {
+    unsafe { clock_res_get(CLOCKID_REALTIME) }
+        .ok()
+        .map(Duration::from_nanos)
+}
+
+pub(crate) fn utcnow() -> Result<UtcTime> {
+    let (secs, nanos) = clock_time_get_split(CLOCKID_REALTIME)?;
+    Ok(unsafe { UtcTime::new_unchecked(secs, nanos) })
+}
+
+pub(crate) const fn clock_implemented(source: ClockSource) -> bool {
+    matches!(source, ClockSource::Realtime | ClockSource::Monotonic)
+}
+
+pub(crate) const fn clock_infallible(_: ClockSource) -> bool {
+    false
+}
+
+pub(crate) fn now_from(source: ClockSource) -> core::result::Result<RawClockReading, ClockError> {
+    let clock_id = match source {
+        ClockSource::Realtime => CLOCKID_REALTIME,
+        ClockSource::Monotonic => CLOCKID_MONOTONIC,
+        _ => return Err(ClockError::unsupported(source)),
+    };
+    match clock_time_get_split(clock_id) {
+        Ok((secs, nanos)) => Ok(unsafe { RawClockReading::new_unchecked(source, secs, nanos) }),
+        Err(err) => Err(ClockError::new(source, err.kind(), err.raw_os_error())),
+    }
+}
+
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
-pub(crate) fn utcnow() -> Result<UtcTime> {
-    let nanos = unsafe { clock_time_get(CLOCKID_REALTIME, 100) }.map_err(OsError)?;
+fn clock_time_get_split(clock_id: Clockid) -> core::result::Result<(i64, u32), OsError> {
+    let nanos = unsafe { clock_time_get(clock_id, 100) }.map_err(OsError)?;
     let secs = nanos.div_euclid(1_000_000_000) as i64;
     let nanos = nanos.rem_euclid(1_000_000_000) as u32;
-    Ok(unsafe { UtcTime::new_unchecked(secs, nanos) })
+    Ok((secs, nanos))
 }
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct OsError(Errno);
 
+impl OsError {
+    #[allow(clippy::unnecessary_wraps)]
+    #[inline]
+    pub(crate) fn raw_os_error(self) -> Option<i32> {
+        Some(self.0.raw().into())
+    }
+
+    pub(crate) fn kind(self) -> ErrorKind {
+        match self.0.raw() {
+            raw if raw == ERRNO_NOSYS.raw() || raw == ERRNO_NOTSUP.raw() => ErrorKind::Unsupported,
+            raw if raw == ERRNO_PERM.raw()
+                || raw == ERRNO_ACCES.raw()
+                || raw == ERRNO_NOTCAPABLE.raw() =>
+            {
+                ErrorKind::PermissionDenied
+            },
+            raw if raw == ERRNO_INVAL.raw() => ErrorKind::InvalidClock,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
 impl fmt::Display for OsError {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1,11 +1,21 @@
 use core::convert::Infallible;
 use core::fmt;
+use core::time::Duration;
 
-use crate::{Result, UtcTime};
+use crate::{Backend, ClockError, ClockSource, ErrorKind, RawClockReading, Result, UtcTime};
 
 pub(crate) const IMPLEMENTED: bool = true;
 pub(crate) const INFALLIBLE: bool = true;
 
+pub(crate) const BACKEND: Backend = Backend::Web;
+pub(crate) const CLOCK_ID: &str = "Date.now";
+
+/// `Date.now()` returns whole milliseconds
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn resolution() -> Option<Duration> {
+    Some(Duration::from_millis(1))
+}
+
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_sign_loss)]
 #[allow(clippy::unnecessary_wraps)]
@@ -16,9 +26,42 @@ pub(crate) fn utcnow() -> Result<UtcTime> {
     Ok(unsafe { UtcTime::new_unchecked(secs, nanos) })
 }
 
+pub(crate) const fn clock_implemented(source: ClockSource) -> bool {
+    matches!(source, ClockSource::Realtime)
+}
+
+pub(crate) const fn clock_infallible(source: ClockSource) -> bool {
+    clock_implemented(source)
+}
+
+pub(crate) fn now_from(source: ClockSource) -> core::result::Result<RawClockReading, ClockError> {
+    match (source, utcnow()) {
+        (ClockSource::Realtime, Ok(now)) => {
+            Ok(
+                unsafe {
+                    RawClockReading::new_unchecked(source, now.as_secs(), now.subsec_nanos())
+                },
+            )
+        },
+        _ => Err(ClockError::unsupported(source)),
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct OsError(Infallible);
 
+impl OsError {
+    #[inline]
+    pub(crate) fn raw_os_error(self) -> Option<i32> {
+        match self.0 {}
+    }
+
+    #[inline]
+    pub(crate) fn kind(self) -> ErrorKind {
+        match self.0 {}
+    }
+}
+
 impl fmt::Display for OsError {
     #[inline]
     fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
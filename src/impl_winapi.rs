@@ -1,33 +1,100 @@
 use core::convert::Infallible;
 use core::fmt;
+use core::time::Duration;
 
 use winapi::shared::minwindef::FILETIME;
-use winapi::um::sysinfoapi::GetSystemTimePreciseAsFileTime;
+use winapi::um::profileapi::{QueryPerformanceCounter, QueryPerformanceFrequency};
+use winapi::um::sysinfoapi::{
+    GetSystemTimeAsFileTime, GetSystemTimePreciseAsFileTime, GetTickCount64,
+};
+use winapi::um::winnt::LARGE_INTEGER;
 
-use crate::{Result, UtcTime};
+use crate::{Backend, ClockError, ClockSource, ErrorKind, RawClockReading, Result, UtcTime};
 
 pub(crate) const IMPLEMENTED: bool = true;
 pub(crate) const INFALLIBLE: bool = true;
 
+pub(crate) const BACKEND: Backend = Backend::Winapi;
+pub(crate) const CLOCK_ID: &str = "GetSystemTimePreciseAsFileTime";
+
+/// A `FILETIME` counts in units of 100 ns
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn resolution() -> Option<Duration> {
+    Some(Duration::from_nanos(100))
+}
+
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn utcnow() -> Result<UtcTime> {
+    let (secs, nanos) = filetime(GetSystemTimePreciseAsFileTime);
+    Ok(unsafe { UtcTime::new_unchecked(secs, nanos) })
+}
+
+pub(crate) const fn clock_implemented(source: ClockSource) -> bool {
+    !matches!(source, ClockSource::Tai)
+}
+
+pub(crate) const fn clock_infallible(source: ClockSource) -> bool {
+    clock_implemented(source)
+}
+
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn now_from(source: ClockSource) -> core::result::Result<RawClockReading, ClockError> {
+    let (secs, nanos) = match source {
+        ClockSource::Realtime => filetime(GetSystemTimePreciseAsFileTime),
+        ClockSource::RealtimeCoarse => filetime(GetSystemTimeAsFileTime),
+        ClockSource::Monotonic => performance_counter(),
+        ClockSource::Boottime => {
+            let millis = unsafe { GetTickCount64() };
+            ((millis / 1000) as i64, (millis % 1000) as u32 * 1_000_000)
+        },
+        ClockSource::Tai => return Err(ClockError::unsupported(source)),
+    };
+    Ok(unsafe { RawClockReading::new_unchecked(source, secs, nanos) })
+}
+
+fn filetime(get: unsafe extern "system" fn(*mut FILETIME)) -> (i64, u32) {
     let mut now = FILETIME {
         dwLowDateTime: 0,
         dwHighDateTime: 0,
     };
-    unsafe { GetSystemTimePreciseAsFileTime(&mut now) };
-    let now = (now.dwHighDateTime as u64 * 0x1_0000_0000) + now.dwLowDateTime as u64;
+    unsafe { get(&mut now) };
+    let now = (u64::from(now.dwHighDateTime) << 32) | u64::from(now.dwLowDateTime);
+    let now = UtcTime::from_filetime(now);
+    (now.as_secs(), now.subsec_nanos())
+}
 
-    // https://stackoverflow.com/a/19709740/416224
-    // epoch is Jan. 1, 1601: 134774 days to Jan. 1, 1970
-    let secs = now.div_euclid(10_000_000) as i64 - 11644473600;
-    let nanos = now.rem_euclid(10_000_000) as u32 * 100;
-    Ok(unsafe { UtcTime::new_unchecked(secs, nanos) })
+/// QueryPerformanceCounter() cannot fail since Windows XP
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+fn performance_counter() -> (i64, u32) {
+    let mut counter: LARGE_INTEGER = unsafe { core::mem::zeroed() };
+    let mut frequency: LARGE_INTEGER = unsafe { core::mem::zeroed() };
+    let (counter, frequency) = unsafe {
+        let _ = QueryPerformanceCounter(&mut counter);
+        let _ = QueryPerformanceFrequency(&mut frequency);
+        (*counter.QuadPart(), *frequency.QuadPart())
+    };
+    let secs = counter.div_euclid(frequency);
+    let nanos = i128::from(counter.rem_euclid(frequency)) * 1_000_000_000 / i128::from(frequency);
+    (secs, nanos as u32)
 }
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct OsError(Infallible);
 
+impl OsError {
+    #[inline]
+    pub(crate) fn raw_os_error(self) -> Option<i32> {
+        match self.0 {}
+    }
+
+    #[inline]
+    pub(crate) fn kind(self) -> ErrorKind {
+        match self.0 {}
+    }
+}
+
 impl fmt::Display for OsError {
     #[inline]
     fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
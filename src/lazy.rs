@@ -0,0 +1,37 @@
+use core::marker::PhantomData;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// A lazily allocated value, that can be used in a `static`
+///
+/// `RwLock::new()` and `Mutex::new()` are only `const` since Rust 1.63.
+/// The value is allocated on first use, and never freed.
+/// `PhantomData<T>` makes the type only `Sync` if `T` is `Sync`.
+pub(crate) struct LazyBox<T>(AtomicPtr<T>, PhantomData<T>);
+
+impl<T> LazyBox<T> {
+    pub(crate) const fn new() -> Self {
+        Self(AtomicPtr::new(ptr::null_mut()), PhantomData)
+    }
+
+    pub(crate) fn get_or_init(&self, init: impl FnOnce() -> T) -> &T {
+        let mut value = self.0.load(Ordering::Acquire);
+        if value.is_null() {
+            let new = Box::into_raw(Box::new(init()));
+            value = match self.0.compare_exchange(
+                ptr::null_mut(),
+                new,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => new,
+                Err(existing) => {
+                    // Another thread was faster.
+                    drop(unsafe { Box::from_raw(new) });
+                    existing
+                },
+            };
+        }
+        unsafe { &*value }
+    }
+}
@@ -42,6 +42,7 @@
 //! If this is true for the current target, then the constant [`INFALLIBLE`] will be `true`.
 //!
 //! If the target platform is not supported, then [`utcnow()`] will always return an error instead of failing to compile.
+//! If the feature `"std"` is enabled, then [`SystemTime::now()`](std::time::SystemTime::now) is used instead.
 //! Use the library with `default-features = false` and without the feature `"fallback"` to get a compile-time error instead.
 //!
 //! The feature `"std"` (enabled by default) is only needed if you need the [`Error`] type to implement [`std::error::Error`].
@@ -84,7 +85,12 @@
 //!
 //! `utcnow` has the following optional features:
 //!
-//! * `serde`, which implements [`serde::Deserialize`] and [`serde::Serialize`] for [`UtcTime`].
+//! * `serde`, which implements [`::serde::Deserialize`] and [`::serde::Serialize`] for [`UtcTime`],
+//!   and adds the module [`utcnow::serde`](crate::serde) with alternative representations.
+//!
+//! * `serde-human-readable`, which makes the default implementation write an RFC 3339 string
+//!   if the serializer [is human readable](::serde::Serializer::is_human_readable).
+//!   Deserialization still accepts the `[secs, nanos]` tuple, too.
 //!
 //! * `arbitrary`, which implements the [`arbitrary::Arbitrary`] trait for [`UtcTime`].
 //!
@@ -94,8 +100,27 @@
 //!
 //! * `rkyv`, which implements the [`rkyv::Archive`], [`rkyv::Serialize`], and [`rkyv::Deserialize`] for [`UtcTime`].
 //!
+//! * `rkyv-08`, which implements `Archive`, `Serialize`, and `Deserialize` of rkyv 0.8 for [`UtcTime`].
+//!   The archived value is stored in little-endian byte order, and validated by `rkyv::access()`.
+//!
+//! * `rkyv-validation`, which implements `rkyv::CheckBytes` for the archived [`UtcTime`],
+//!   so untrusted input can be accessed with `rkyv::check_archived_root()`.
+//!
+//! * `prost`, which implements conversions between [`UtcTime`] and `prost_types::Timestamp`,
+//!   and adds [`UtcTime::to_proto3_json()`] and [`UtcTime::parse_proto3_json()`].
+//!
+//! * `chrono`, `time` and `jiff`, which implement `From` and `TryFrom` conversions between [`UtcTime`] and
+//!   `chrono::DateTime<Utc>`, `time::OffsetDateTime` and `time::UtcDateTime`, and `jiff::Timestamp`.
+//!   The conversions are exact to the nanosecond, and fail if the time is out of range for the target type.
+//!
 //! * `castaway`, which implements the [`castaway::LifetimeFree`] trait for [`UtcTime`].
 //!
+//! * `mock`, which lets you override the time returned by [`utcnow()`] for testing, see [`mock`].
+//!   Implies `std`. Don't enable this feature outside of tests.
+//!
+//! * `custom`, which lets you provide the time on targets without a native implementation,
+//!   e.g. by reading a real-time clock on a bare-metal target, see [`register_custom_utcnow!`].
+//!
 
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
@@ -113,21 +138,57 @@
 #![warn(unused_lifetimes)]
 #![warn(unused_results)]
 
+/// The `?` operator for `const fn`s
+macro_rules! const_try {
+    ($expr:expr) => {
+        match $expr {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        }
+    };
+}
+
+mod backend;
 #[cfg(docsrs)]
 #[cfg_attr(docsrs, doc(cfg(any())))]
 pub mod changelog;
+mod civil;
+mod clock;
+mod clock_source;
+#[cfg(feature = "custom")]
+mod custom;
+mod decimal;
+mod delta;
 #[cfg(feature = "arbitrary")]
 mod feat_arbitrary;
 #[cfg(feature = "castaway")]
 mod feat_castaway;
+#[cfg(feature = "chrono")]
+mod feat_chrono;
+#[cfg(feature = "jiff")]
+mod feat_jiff;
 #[cfg(feature = "proptest")]
 mod feat_proptest;
+#[cfg(feature = "prost")]
+mod feat_prost;
 #[cfg(feature = "quickcheck")]
 mod feat_quickcheck;
 #[cfg(feature = "rkyv")]
 mod feat_rkyv;
+#[cfg(feature = "rkyv-08")]
+mod feat_rkyv_08;
 #[cfg(feature = "serde")]
 mod feat_serde;
+#[cfg(feature = "time")]
+mod feat_time;
+mod gnss;
+#[cfg(feature = "std")]
+mod lazy;
+mod microsoft;
+#[cfg(feature = "mock")]
+pub mod mock;
+mod ntp;
+#[cfg(not(all(feature = "custom", utcnow_force_custom)))]
 #[cfg_attr(
     any(
         target_os = "dragonfly",
@@ -159,6 +220,16 @@ mod feat_serde;
     path = "impl_web.rs"
 )]
 mod platform;
+// Test the `"custom"` implementation on targets that have a native implementation.
+#[cfg(all(feature = "custom", utcnow_force_custom))]
+#[path = "platform.rs"]
+mod platform;
+mod ptp;
+mod rfc3339;
+mod round;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod tai;
 #[cfg(test)]
 mod test;
 mod u30;
@@ -169,10 +240,27 @@ use core::time::Duration;
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+pub use crate::backend::{Backend, BackendInfo, backend_info};
+pub use crate::civil::{CivilDateTime, Weekday};
+pub use crate::clock::{Clock, FixedClock, OffsetClock, SystemClock};
+#[cfg(feature = "std")]
+pub use crate::clock::{ManualClock, SteppingClock};
+pub use crate::clock_source::{ClockError, ClockSource, RawClockReading, now_from};
+pub use crate::decimal::Decimal;
+pub use crate::delta::UtcDelta;
+pub use crate::gnss::{BeiDouTime, GalileoTime, GpsTime};
+pub use crate::ntp::{NtpShort32, NtpTimestamp64};
 use crate::platform::OsError;
+pub use crate::ptp::PtpTimestamp;
+pub use crate::rfc3339::{Precision, Rfc3339};
+pub use crate::round::{Interval, RoundingMode, TimeUnit};
+pub use crate::tai::{LeapSecond, LeapSecondTable, ParseLeapSecondsError, TaiTime, tai_offset_at};
 use crate::u30::U30;
 
 /// `true` if getting the time is implemented for the target platform
+///
+/// This is also `true` on unsupported targets if the features `"std"` and `"fallback"` are enabled,
+/// because then [`SystemTime::now()`](std::time::SystemTime::now) is used.
 pub const IMPLEMENTED: bool = platform::IMPLEMENTED;
 
 /// `true` if [`utcnow()`] cannot fail
@@ -195,6 +283,16 @@ impl UtcTime {
         secs: 0,
         nanos: U30::ZERO,
     };
+    /// The latest representable [`UtcTime`], about 292 billion years after 1970-01-01.
+    pub const MAX: UtcTime = UtcTime {
+        secs: i64::MAX,
+        nanos: U30::MAX,
+    };
+    /// The earliest representable [`UtcTime`], about 292 billion years before 1970-01-01.
+    pub const MIN: UtcTime = UtcTime {
+        secs: i64::MIN,
+        nanos: U30::ZERO,
+    };
 
     /// Get the current time
     ///
@@ -246,6 +344,7 @@ impl UtcTime {
     /// let timestamp = UtcTime::new(1_659_545_693, 895_531_827).unwrap();
     /// ```
     #[must_use]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
     #[const_fn::const_fn("1.56")]
     pub fn new(secs: i64, nanos: u32) -> Option<Self> {
         const NANOS_PER_SEC: u32 = 1_000_000_000;
@@ -306,6 +405,138 @@ impl UtcTime {
         Some(unsafe { Self::new_unchecked(secs, nanos) })
     }
 
+    /// Build a new [`UtcTime`] from whole seconds since epoch (1970-01-01 in UTC)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// const TIMESTAMP: UtcTime = UtcTime::from_secs(1_659_545_693);
+    /// assert_eq!(TIMESTAMP.as_secs(), 1_659_545_693);
+    /// assert_eq!(TIMESTAMP.subsec_nanos(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_secs(secs: i64) -> Self {
+        Self {
+            secs,
+            nanos: U30::ZERO,
+        }
+    }
+
+    /// Build a new [`UtcTime`] from milliseconds since epoch (1970-01-01 in UTC)
+    ///
+    /// This is the inverse of [`UtcTime::as_millis()`].
+    /// Negative values denote times before 1970, e.g. `-1` is 1969-12-31T23:59:59.999Z.
+    /// Returns [`None`] if the value is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let timestamp = UtcTime::from_millis(-1).unwrap();
+    /// assert_eq!(timestamp.as_secs(), -1);
+    /// assert_eq!(timestamp.subsec_millis(), 999);
+    /// assert_eq!(timestamp.as_millis(), -1);
+    /// ```
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn from_millis(millis: i128) -> Option<Self> {
+        Self::from_units(millis, 1_000)
+    }
+
+    /// Build a new [`UtcTime`] from microseconds since epoch (1970-01-01 in UTC)
+    ///
+    /// This is the inverse of [`UtcTime::as_micros()`].
+    /// Negative values denote times before 1970.
+    /// Returns [`None`] if the value is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let timestamp = UtcTime::from_micros(1_659_545_693_895_531).unwrap();
+    /// assert_eq!(timestamp.as_secs(), 1_659_545_693);
+    /// assert_eq!(timestamp.subsec_nanos(), 895_531_000);
+    /// ```
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn from_micros(micros: i128) -> Option<Self> {
+        Self::from_units(micros, 1_000_000)
+    }
+
+    /// Build a new [`UtcTime`] from nanoseconds since epoch (1970-01-01 in UTC)
+    ///
+    /// This is the inverse of [`UtcTime::as_nanos()`].
+    /// Negative values denote times before 1970.
+    /// Returns [`None`] if the value is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let timestamp = UtcTime::from_nanos(-1_500_000_000).unwrap();
+    /// assert_eq!(timestamp.as_secs(), -2);
+    /// assert_eq!(timestamp.subsec_nanos(), 500_000_000);
+    /// assert_eq!(UtcTime::from_nanos(i128::MAX), None);
+    /// ```
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn from_nanos(nanos: i128) -> Option<Self> {
+        Self::from_units(nanos, 1_000_000_000)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+    #[const_fn::const_fn("1.56")]
+    fn from_units(value: i128, per_sec: i128) -> Option<Self> {
+        let secs = value.div_euclid(per_sec);
+        if secs < i64::MIN as i128 || secs > i64::MAX as i128 {
+            return None;
+        }
+        let nanos = value.rem_euclid(per_sec) * (1_000_000_000 / per_sec);
+        Some(unsafe { Self::new_unchecked(secs as i64, nanos as u32) })
+    }
+
+    /// Build a new [`UtcTime`] from fractional seconds since epoch (1970-01-01 in UTC)
+    ///
+    /// Just like every [`UtcTime`], the result is rounded down to the previous whole second plus a
+    /// non-negative number of nanoseconds, e.g. `-0.25` becomes `-1 s + 750_000_000 ns`.
+    /// The nanoseconds are rounded to the nearest nanosecond, with ties rounded up.
+    ///
+    /// Returns [`None`] if the value is [NaN](f64::NAN), infinite, or out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let timestamp = UtcTime::from_secs_f64(-0.25).unwrap();
+    /// assert_eq!(timestamp.as_secs(), -1);
+    /// assert_eq!(timestamp.subsec_nanos(), 750_000_000);
+    /// assert_eq!(UtcTime::from_secs_f64(f64::NAN), None);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn from_secs_f64(secs: f64) -> Option<Self> {
+        // 2^63, the first value that is out of range, is exactly representable as f64
+        const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+        if !(-LIMIT..LIMIT).contains(&secs) {
+            return None;
+        }
+
+        // `f64::floor()` is not available in `no_std`
+        let mut whole = secs as i64;
+        if whole as f64 > secs {
+            whole -= 1;
+        }
+        // `secs - whole` is exact: both values have the same exponent, or the fraction is zero
+        let nanos = ((secs - whole as f64) * 1_000_000_000.0 + 0.5) as u32;
+        Self::new(whole, nanos)
+    }
+
     /// Total number of whole seconds since epoch (1970-01-01 in UTC)
     ///
     /// # Example
@@ -378,6 +609,25 @@ impl UtcTime {
         (self.secs as i128 * 1_000_000_000) + (self.nanos.get() as i128)
     }
 
+    /// Fractional number of seconds since epoch (1970-01-01 in UTC)
+    ///
+    /// The result is rounded, because an [`f64`] has only 53 significant bits.
+    /// The value is exact to the nanosecond only for about ±104 days around the epoch.
+    /// For current dates the resolution is about 240 ns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let timestamp = UtcTime::new(-1, 750_000_000).unwrap();
+    /// assert_eq!(timestamp.as_secs_f64(), -0.25);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_secs_f64(self) -> f64 {
+        (self.as_nanos() as f64) / 1_000_000_000.0
+    }
+
     /// Fractional number of milliseconds since epoch (1970-01-01 in UTC)
     ///
     /// # Example
@@ -445,11 +695,12 @@ impl UtcTime {
     /// let duration = now.into_duration().unwrap();
     /// ```
     #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
     #[const_fn::const_fn("1.58")]
     pub fn into_duration(self) -> Result<Duration, ConversionError> {
         let secs = match self.secs {
             secs @ 0..=i64::MAX => secs as u64,
-            _ => return Err(ConversionError),
+            _ => return Err(ConversionError::NEGATIVE),
         };
         Ok(Duration::new(secs, self.nanos.get()))
     }
@@ -474,7 +725,7 @@ impl UtcTime {
     pub fn into_system_time(self) -> Result<SystemTime, ConversionError> {
         SystemTime::UNIX_EPOCH
             .checked_add(self.into_duration()?)
-            .ok_or(ConversionError)
+            .ok_or(ConversionError::OVERFLOW)
     }
 }
 
@@ -489,60 +740,51 @@ impl TryFrom<&str> for UtcTime {
     type Error = ConversionError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if matches!(value, "" | ".") || !value.is_ascii() {
-            return Err(ConversionError);
+        let s = value.as_bytes();
+        let (negative, mut pos) = match s.first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        let signed = pos > 0;
+
+        // The sign only applies to the whole seconds, see the `Display` implementation.
+        let mut secs: i128 = 0;
+        let mut digits = 0;
+        while let Some(&c) = s.get(pos).filter(|c| c.is_ascii_digit()) {
+            secs = secs * 10 + i128::from(c - b'0');
+            if secs > MAX_SECS_DIGITS {
+                return Err(ConversionError::TOO_MANY_DIGITS);
+            }
+            digits += 1;
+            pos += 1;
         }
+        let has_secs = digits > 0;
+        let secs = if negative { -secs } else { secs };
+        let secs = i64::try_from(secs).map_err(|_| ConversionError::OVERFLOW)?;
 
-        // Only present since 1.52:
-        // let (secs, nanos) = value.split_once('.').unwrap_or((value, ""));
-
-        let (secs, nanos) = match value
-            .as_bytes()
-            .iter()
-            .enumerate()
-            .find(|(_, &c)| c == b'.')
-        {
-            Some((idx, _)) => unsafe {
-                // SAFETY: we checked that `value` is ASCII, and we know that the index is valid
-                (value.get_unchecked(..idx), value.get_unchecked(idx + 1..))
-            },
-            None => (value, ""),
-        };
+        let mut nanos = 0;
+        if s.get(pos) == Some(&b'.') {
+            pos += 1;
+            let mut factor = 100_000_000;
+            while let Some(&c) = s.get(pos).filter(|c| c.is_ascii_digit()) {
+                nanos += u32::from(c - b'0') * factor;
+                factor /= 10;
+                digits += 1;
+                pos += 1;
+            }
+        }
 
-        let secs = match secs {
-            "" => 0,
-            secs => secs.parse().map_err(|_| ConversionError)?,
-        };
-        let nanos = match nanos {
-            "" => 0,
-            nanos => {
-                let (nanos, factor) = if nanos.len() <= 9 {
-                    let factor = match nanos.len() {
-                        8 => 10,
-                        7 => 100,
-                        6 => 1000,
-                        5 => 10000,
-                        4 => 100_000,
-                        3 => 1_000_000,
-                        2 => 10_000_000,
-                        1 => 100_000_000,
-                        _ => 1,
-                    };
-                    (nanos, factor)
-                } else {
-                    // SAFETY: We checked that `value` is ASCII, so every substring is ASCII,
-                    //         and we just checked that `nanos` is longer than 9 bytes.
-                    let nanos = unsafe { nanos.get_unchecked(..9) };
-                    let suffix = unsafe { nanos.get_unchecked(9..) };
-                    if suffix.as_bytes().iter().any(|c| !c.is_ascii_digit()) {
-                        return Err(ConversionError);
-                    }
-                    (nanos, 1)
-                };
-                nanos.parse::<u32>().map_err(|_| ConversionError)? * factor
-            },
-        };
-        Ok(unsafe { Self::new_unchecked(secs, nanos) })
+        if pos < s.len() {
+            Err(ConversionError::invalid_character(pos))
+        } else if digits == 0 {
+            Err(ConversionError::EMPTY)
+        } else if signed && !has_secs {
+            // Reject "-.5", because the sign would not apply to the fraction.
+            Err(ConversionError::invalid_character(1))
+        } else {
+            Ok(unsafe { Self::new_unchecked(secs, nanos) })
+        }
     }
 }
 
@@ -575,6 +817,13 @@ impl core::str::FromStr for UtcTime {
 /// ```
 #[inline]
 pub fn utcnow() -> Result<UtcTime> {
+    #[cfg(feature = "mock")]
+    {
+        if let Some(now) = mock::utcnow() {
+            return now;
+        }
+    }
+
     platform::utcnow()
 }
 
@@ -602,7 +851,7 @@ impl TryFrom<Duration> for UtcTime {
 
     #[inline]
     fn try_from(value: Duration) -> Result<Self, ConversionError> {
-        Self::from_duration(value).ok_or(ConversionError)
+        Self::from_duration(value).ok_or(ConversionError::OVERFLOW)
     }
 }
 
@@ -612,7 +861,10 @@ impl TryFrom<SystemTime> for UtcTime {
 
     #[inline]
     fn try_from(value: SystemTime) -> Result<Self, ConversionError> {
-        Self::from_system_time(value).ok_or(ConversionError)
+        match value.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(value) => Self::from_duration(value).ok_or(ConversionError::OVERFLOW),
+            Err(_) => Err(ConversionError::NEGATIVE),
+        }
     }
 }
 
@@ -631,6 +883,35 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 #[derive(Debug, Clone, Copy)]
 pub struct Error(OsError);
 
+impl Error {
+    /// The raw error code of the operating system, if there is any
+    ///
+    /// On targets that use `libc` this is the `errno` value, on WASI it is the `wasi::Errno` value.
+    #[must_use]
+    #[inline]
+    pub fn raw_os_error(self) -> Option<i32> {
+        self.0.raw_os_error()
+    }
+
+    /// The category of the error
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use utcnow::{ErrorKind, utcnow};
+    /// match utcnow() {
+    ///     Ok(now) => println!("now = {}", now),
+    ///     Err(err) if err.kind() == ErrorKind::NotImplemented => println!("Unknown platform"),
+    ///     Err(err) => println!("Could not get the time: {}", err),
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn kind(self) -> ErrorKind {
+        self.0.kind()
+    }
+}
+
 impl fmt::Display for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -657,17 +938,154 @@ const _: () = {
     impl core::error::Error for Error {}
 };
 
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> Self {
+        match err.raw_os_error() {
+            Some(code) => std::io::Error::from_raw_os_error(code),
+            None => std::io::Error::new(io_error_kind(err.kind()), err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn io_error_kind(kind: ErrorKind) -> std::io::ErrorKind {
+    #[rustversion::since(1.53)]
+    const UNSUPPORTED: std::io::ErrorKind = std::io::ErrorKind::Unsupported;
+    #[rustversion::before(1.53)]
+    const UNSUPPORTED: std::io::ErrorKind = std::io::ErrorKind::Other;
+
+    match kind {
+        ErrorKind::Unsupported | ErrorKind::NotImplemented => UNSUPPORTED,
+        ErrorKind::PermissionDenied => std::io::ErrorKind::PermissionDenied,
+        ErrorKind::InvalidClock => std::io::ErrorKind::InvalidInput,
+        ErrorKind::Other => std::io::ErrorKind::Other,
+    }
+}
+
+/// The category of an [`Error`]
+///
+/// Returned by [`Error::kind()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The operating system does not support querying the clock, e.g. `ENOSYS`
+    Unsupported,
+    /// The target platform is not implemented by this library, see [`IMPLEMENTED`]
+    NotImplemented,
+    /// The process is not allowed to query the clock, e.g. `EPERM`
+    PermissionDenied,
+    /// The operating system does not know the requested clock, e.g. `EINVAL`
+    InvalidClock,
+    /// Any other error
+    Other,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ErrorKind::Unsupported => "operation not supported by the operating system",
+            ErrorKind::NotImplemented => "platform is not implemented",
+            ErrorKind::PermissionDenied => "permission denied",
+            ErrorKind::InvalidClock => "invalid clock",
+            ErrorKind::Other => "other error",
+        })
+    }
+}
+
+/// Whole seconds with more than 19 significant digits cannot fit into an [`i64`]
+pub(crate) const MAX_SECS_DIGITS: i128 = 9_999_999_999_999_999_999;
+
 /// Could not convert from or to a [`UtcTime`]
 ///
+/// Use [`ConversionError::kind()`] to find out why the conversion failed.
+///
 /// You cannot convert a negative [`UtcTime`]  (i.e. before 1970-01-01) into a [`SystemTime`] or [`Duration`].
 /// You cannot convert a [`SystemTime`] or [`Duration`] later than year 292 billion into a [`UtcTime`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct ConversionError;
+pub struct ConversionError(ConversionErrorKind);
+
+impl ConversionError {
+    pub(crate) const EMPTY: Self = Self(ConversionErrorKind::Empty);
+    pub(crate) const NEGATIVE: Self = Self(ConversionErrorKind::Negative);
+    pub(crate) const OUT_OF_RANGE: Self = Self(ConversionErrorKind::OutOfRange);
+    pub(crate) const OVERFLOW: Self = Self(ConversionErrorKind::Overflow);
+    pub(crate) const TOO_MANY_DIGITS: Self = Self(ConversionErrorKind::TooManyDigits);
+
+    #[inline]
+    pub(crate) const fn invalid_character(index: usize) -> Self {
+        Self(ConversionErrorKind::InvalidCharacter { index })
+    }
+
+    /// The reason why the conversion failed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{ConversionErrorKind, UtcTime};
+    /// let err = "12.3x".parse::<UtcTime>().unwrap_err();
+    /// assert_eq!(err.kind(), ConversionErrorKind::InvalidCharacter { index: 4 });
+    ///
+    /// let err = UtcTime::new(-1, 0).unwrap().into_duration().unwrap_err();
+    /// assert_eq!(err.kind(), ConversionErrorKind::Negative);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn kind(self) -> ConversionErrorKind {
+        self.0
+    }
+}
+
+impl From<ConversionError> for ConversionErrorKind {
+    #[inline]
+    fn from(value: ConversionError) -> Self {
+        value.0
+    }
+}
 
 impl fmt::Display for ConversionError {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("cannot convert a negative UtcTime")
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// The reason why a [`ConversionError`] occurred
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ConversionErrorKind {
+    /// A negative [`UtcTime`] (i.e. before 1970-01-01) cannot be converted into the target type
+    Negative,
+    /// The value is too large or too small to be represented in the target type
+    Overflow,
+    /// The input contains an unexpected character
+    ///
+    /// `index` is the byte offset of the character.
+    /// If the input ended prematurely, then `index` is the length of the input.
+    InvalidCharacter {
+        /// Byte offset of the unexpected character in the input
+        index: usize,
+    },
+    /// The input does not contain any digits
+    Empty,
+    /// A number in the input has more digits than any valid value could have
+    TooManyDigits,
+    /// A field of the input, e.g. the month or the UTC offset, is outside of its valid range
+    OutOfRange,
+}
+
+impl fmt::Display for ConversionErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Negative => f.write_str("cannot convert a negative UtcTime"),
+            Self::Overflow => f.write_str("value is out of range for the target type"),
+            Self::InvalidCharacter { index } => {
+                write!(f, "unexpected character or end of input at index {}", index)
+            },
+            Self::Empty => f.write_str("input contains no digits"),
+            Self::TooManyDigits => f.write_str("number has too many digits"),
+            Self::OutOfRange => f.write_str("field is out of range"),
+        }
     }
 }
 
@@ -687,11 +1105,44 @@ const _: () = {
         const AUTO_TRAITS: bool = true;
     }
 
+    const _: bool = Backend::AUTO_TRAITS;
+    const _: bool = BeiDouTime::AUTO_TRAITS;
+    const _: bool = BackendInfo::AUTO_TRAITS;
+    const _: bool = CivilDateTime::AUTO_TRAITS;
+    const _: bool = ClockError::AUTO_TRAITS;
+    const _: bool = ClockSource::AUTO_TRAITS;
     const _: bool = ConversionError::AUTO_TRAITS;
+    const _: bool = ConversionErrorKind::AUTO_TRAITS;
+    const _: bool = Decimal::AUTO_TRAITS;
+    const _: bool = FixedClock::AUTO_TRAITS;
+    const _: bool = GalileoTime::AUTO_TRAITS;
+    const _: bool = GpsTime::AUTO_TRAITS;
+    const _: bool = Interval::AUTO_TRAITS;
+    const _: bool = LeapSecond::AUTO_TRAITS;
+    const _: bool = LeapSecondTable::AUTO_TRAITS;
+    #[cfg(feature = "std")]
+    const _: bool = ManualClock::AUTO_TRAITS;
+    const _: bool = NtpShort32::AUTO_TRAITS;
+    const _: bool = NtpTimestamp64::AUTO_TRAITS;
+    const _: bool = OffsetClock::<SystemClock>::AUTO_TRAITS;
     const _: bool = Error::AUTO_TRAITS;
+    const _: bool = ErrorKind::AUTO_TRAITS;
     const _: bool = Option::<U30>::AUTO_TRAITS;
     const _: bool = OsError::AUTO_TRAITS;
+    const _: bool = ParseLeapSecondsError::AUTO_TRAITS;
+    const _: bool = Precision::AUTO_TRAITS;
+    const _: bool = PtpTimestamp::AUTO_TRAITS;
     const _: bool = Result::<U30>::AUTO_TRAITS;
+    const _: bool = RawClockReading::AUTO_TRAITS;
+    const _: bool = Rfc3339::AUTO_TRAITS;
+    const _: bool = RoundingMode::AUTO_TRAITS;
+    #[cfg(feature = "std")]
+    const _: bool = SteppingClock::AUTO_TRAITS;
+    const _: bool = SystemClock::AUTO_TRAITS;
+    const _: bool = TaiTime::AUTO_TRAITS;
+    const _: bool = TimeUnit::AUTO_TRAITS;
     const _: bool = U30::AUTO_TRAITS;
+    const _: bool = UtcDelta::AUTO_TRAITS;
     const _: bool = UtcTime::AUTO_TRAITS;
+    const _: bool = Weekday::AUTO_TRAITS;
 };
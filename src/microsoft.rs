@@ -0,0 +1,298 @@
+use crate::UtcTime;
+
+/// Seconds between 1601-01-01 (`FILETIME` epoch) and 1970-01-01 (Unix epoch), i.e. 134774 days
+const FILETIME_TO_UNIX: i64 = 11_644_473_600;
+/// Seconds between 0001-01-01 (.NET epoch) and 1970-01-01 (Unix epoch)
+const DOTNET_TO_UNIX: i64 = 62_135_596_800;
+/// `DateTime.MaxValue.Ticks`, i.e. 9999-12-31T23:59:59.9999999
+const DOTNET_MAX_TICKS: i64 = 3_155_378_975_999_999_999;
+/// Both formats count in units of 100 ns
+const TICKS_PER_SEC: u64 = 10_000_000;
+
+/// Milliseconds between 1899-12-30 (OLE Automation epoch) and 1970-01-01 (Unix epoch)
+const OLE_TO_UNIX_MILLIS: i64 = 2_209_161_600_000;
+const MILLIS_PER_DAY: i64 = 86_400_000;
+/// 0100-01-01T00:00:00 UTC, the first day an OLE Automation date can represent
+const OLE_MIN_SECS: i64 = -59_011_459_200;
+/// 10000-01-01T00:00:00 UTC, the first day an OLE Automation date cannot represent anymore
+const OLE_END_SECS: i64 = 253_402_300_800;
+
+impl UtcTime {
+    /// Convert a Windows `FILETIME`, i.e. the number of 100 ns intervals since 1601-01-01 in UTC
+    ///
+    /// Every `FILETIME` can be represented as [`UtcTime`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let time = UtcTime::from_filetime(116_444_736_000_000_000 + 1);
+    /// assert_eq!((time.as_secs(), time.subsec_nanos()), (0, 100));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    #[const_fn::const_fn("1.56")]
+    pub fn from_filetime(ticks: u64) -> Self {
+        // `u64::MAX / TICKS_PER_SEC` is less than `i64::MAX - FILETIME_TO_UNIX`
+        let secs = (ticks / TICKS_PER_SEC) as i64 - FILETIME_TO_UNIX;
+        let nanos = (ticks % TICKS_PER_SEC) as u32 * 100;
+        unsafe { Self::new_unchecked(secs, nanos) }
+    }
+
+    /// Convert to a Windows `FILETIME`, i.e. the number of 100 ns intervals since 1601-01-01 in UTC
+    ///
+    /// The nanoseconds are rounded down to a multiple of 100 ns.
+    /// Returns `None` if the time is before 1601-01-01, or after the year 60056.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// assert_eq!(UtcTime::EPOCH.to_filetime(), Some(116_444_736_000_000_000));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    #[const_fn::const_fn("1.56")]
+    pub fn to_filetime(self) -> Option<u64> {
+        let secs = match self.secs.checked_add(FILETIME_TO_UNIX) {
+            Some(secs @ 0..=i64::MAX) => secs as u64,
+            _ => return None,
+        };
+        match secs.checked_mul(TICKS_PER_SEC) {
+            Some(ticks) => ticks.checked_add(self.nanos.get() as u64 / 100),
+            None => None,
+        }
+    }
+
+    /// Convert .NET `DateTime.Ticks`, i.e. the number of 100 ns intervals since 0001-01-01
+    ///
+    /// The ticks are interpreted as UTC. Returns `None` if the value is not in the range of a .NET `DateTime`,
+    /// i.e. if it is negative, or greater than `DateTime.MaxValue.Ticks`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// assert_eq!(UtcTime::from_dotnet_ticks(621_355_968_000_000_000), Some(UtcTime::EPOCH));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::cast_sign_loss)]
+    #[const_fn::const_fn("1.56")]
+    pub fn from_dotnet_ticks(ticks: i64) -> Option<Self> {
+        const TICKS_PER_SEC: i64 = self::TICKS_PER_SEC as i64;
+        match ticks {
+            0..=DOTNET_MAX_TICKS => {
+                let secs = ticks / TICKS_PER_SEC - DOTNET_TO_UNIX;
+                let nanos = (ticks % TICKS_PER_SEC) as u32 * 100;
+                Some(unsafe { Self::new_unchecked(secs, nanos) })
+            },
+            _ => None,
+        }
+    }
+
+    /// Convert to .NET `DateTime.Ticks`, i.e. the number of 100 ns intervals since 0001-01-01
+    ///
+    /// The nanoseconds are rounded down to a multiple of 100 ns.
+    /// Returns `None` if the time is not in the range of a .NET `DateTime`, i.e. the years 1 to 9999.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// assert_eq!(UtcTime::EPOCH.to_dotnet_ticks(), Some(621_355_968_000_000_000));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    #[const_fn::const_fn("1.56")]
+    pub fn to_dotnet_ticks(self) -> Option<i64> {
+        const TICKS_PER_SEC: i64 = self::TICKS_PER_SEC as i64;
+        const MAX_SECS: i64 = DOTNET_MAX_TICKS / TICKS_PER_SEC;
+        match self.secs.checked_add(DOTNET_TO_UNIX) {
+            Some(secs @ 0..=MAX_SECS) => Some(secs * TICKS_PER_SEC + self.nanos.get() as i64 / 100),
+            _ => None,
+        }
+    }
+
+    /// Convert an OLE Automation date, e.g. a `DATE` in COM, or the result of .NET's `DateTime.ToOADate()`
+    ///
+    /// The integral part is the number of days since 1899-12-30, the absolute value of the fractional part
+    /// is the time of the day, e.g. `-1.25` is 1899-12-29T06:00:00.
+    /// The value is rounded to the nearest millisecond, the same as .NET's `DateTime.FromOADate()` does.
+    ///
+    /// Returns `None` if the value is not finite, or not in the years 100 to 9999.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let time = UtcTime::from_ole_date(25_569.5).unwrap();
+    /// assert_eq!(time.rfc3339().to_string(), "1970-01-01T12:00:00Z");
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn from_ole_date(value: f64) -> Option<Self> {
+        if !(value > -657_435.0 && value < 2_958_466.0) {
+            return None;
+        }
+        let half = if value >= 0.0 { 0.5 } else { -0.5 };
+        let mut millis = (value * MILLIS_PER_DAY as f64 + half) as i64;
+        if millis < 0 {
+            // the fraction counts forward from the start of the day
+            millis -= (millis % MILLIS_PER_DAY) * 2;
+        }
+        let millis = millis - OLE_TO_UNIX_MILLIS;
+        let secs = millis.div_euclid(1_000);
+        let nanos = millis.rem_euclid(1_000) as u32 * 1_000_000;
+        Some(unsafe { Self::new_unchecked(secs, nanos) })
+    }
+
+    /// Convert to an OLE Automation date, e.g. a `DATE` in COM, or the input of .NET's `DateTime.FromOADate()`
+    ///
+    /// See [`UtcTime::from_ole_date()`] for the format.
+    /// The value is rounded down to a whole millisecond, the same as .NET's `DateTime.ToOADate()` does.
+    ///
+    /// Returns `None` if the time is not in the years 100 to 9999.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let time = UtcTime::parse_rfc3339("1899-12-29T06:00:00Z").unwrap();
+    /// assert_eq!(time.to_ole_date(), Some(-1.25));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_ole_date(self) -> Option<f64> {
+        if self.secs < OLE_MIN_SECS || self.secs >= OLE_END_SECS {
+            return None;
+        }
+        let mut millis = self.as_millis() as i64 + OLE_TO_UNIX_MILLIS;
+        if millis < 0 {
+            // the fraction counts forward from the start of the day
+            let frac = millis % MILLIS_PER_DAY;
+            if frac != 0 {
+                millis -= (MILLIS_PER_DAY + frac) * 2;
+            }
+        }
+        Some(millis as f64 / MILLIS_PER_DAY as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CivilDateTime, UtcTime};
+
+    fn utc(s: &str) -> UtcTime {
+        UtcTime::parse_rfc3339(s).unwrap()
+    }
+
+    fn civil(year: i64, month: u8, day: u8, time: (u8, u8, u8, u32)) -> UtcTime {
+        let (hour, minute, second, nanosecond) = time;
+        let civil = CivilDateTime::new(year, month, day, hour, minute, second, nanosecond);
+        UtcTime::from_civil(civil.unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_filetime() {
+        const EPOCH: UtcTime = UtcTime::from_filetime(116_444_736_000_000_000);
+        assert_eq!(EPOCH, UtcTime::EPOCH);
+
+        let start = UtcTime::from_filetime(0);
+        assert_eq!(start, utc("1601-01-01T00:00:00Z"));
+        assert_eq!(start.to_filetime(), Some(0));
+        assert_eq!(
+            start
+                .checked_sub_duration(core::time::Duration::from_nanos(1))
+                .unwrap()
+                .to_filetime(),
+            None
+        );
+
+        let end = UtcTime::from_filetime(u64::MAX);
+        assert_eq!(end, civil(60056, 5, 28, (5, 36, 10, 955_161_500)));
+        assert_eq!(end.to_filetime(), Some(u64::MAX));
+        assert_eq!(
+            UtcTime::new(end.as_secs() + 1, 0).unwrap().to_filetime(),
+            None
+        );
+        assert_eq!(UtcTime::MAX.to_filetime(), None);
+        assert_eq!(UtcTime::MIN.to_filetime(), None);
+
+        let time = utc("2024-02-29T12:34:56.123456789Z");
+        let rounded = utc("2024-02-29T12:34:56.1234567Z");
+        assert_eq!(UtcTime::from_filetime(time.to_filetime().unwrap()), rounded);
+    }
+
+    #[test]
+    fn test_dotnet_ticks() {
+        const START: Option<UtcTime> = UtcTime::from_dotnet_ticks(0);
+        assert_eq!(START, Some(utc("0001-01-01T00:00:00Z")));
+        assert_eq!(START.unwrap().to_dotnet_ticks(), Some(0));
+        assert_eq!(UtcTime::from_dotnet_ticks(-1), None);
+
+        let end = UtcTime::from_dotnet_ticks(3_155_378_975_999_999_999).unwrap();
+        assert_eq!(end, utc("9999-12-31T23:59:59.9999999Z"));
+        assert_eq!(end.to_dotnet_ticks(), Some(3_155_378_975_999_999_999));
+        assert_eq!(UtcTime::from_dotnet_ticks(3_155_378_976_000_000_000), None);
+        assert_eq!(civil(10000, 1, 1, (0, 0, 0, 0)).to_dotnet_ticks(), None);
+        assert_eq!(
+            civil(0, 12, 31, (23, 59, 59, 999_999_900)).to_dotnet_ticks(),
+            None
+        );
+        assert_eq!(UtcTime::MAX.to_dotnet_ticks(), None);
+        assert_eq!(UtcTime::MIN.to_dotnet_ticks(), None);
+
+        // DateTime(2024, 2, 29, 12, 34, 56, 123).Ticks
+        let time = utc("2024-02-29T12:34:56.123Z");
+        assert_eq!(time.to_dotnet_ticks(), Some(638_448_068_961_230_000));
+    }
+
+    #[test]
+    fn test_ole_date() {
+        for &(ole, time) in &[
+            (0.0, "1899-12-30T00:00:00Z"),
+            (2.0, "1900-01-01T00:00:00Z"),
+            (25_569.0, "1970-01-01T00:00:00Z"),
+            (45_351.524_259_259_26, "2024-02-29T12:34:56Z"),
+            (-1.25, "1899-12-29T06:00:00Z"),
+            (-657_434.0, "0100-01-01T00:00:00Z"),
+            (2_958_465.999_988_426, "9999-12-31T23:59:59Z"),
+        ] {
+            let time = utc(time);
+            assert_eq!(UtcTime::from_ole_date(ole), Some(time), "{}", ole);
+            let back = time.to_ole_date().unwrap();
+            assert!((back - ole).abs() < 1e-9, "{} != {}", back, ole);
+        }
+
+        // the day -0 is the same as the day 0
+        assert_eq!(
+            UtcTime::from_ole_date(-0.5),
+            Some(utc("1899-12-30T12:00:00Z")),
+        );
+        assert_eq!(
+            UtcTime::from_ole_date(0.000_000_01),
+            Some(utc("1899-12-30T00:00:00.001Z")),
+        );
+        assert_eq!(
+            utc("1970-01-01T00:00:00.0009Z").to_ole_date(),
+            Some(25_569.0),
+        );
+        for &ole in &[
+            -657_435.0,
+            2_958_466.0,
+            f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ] {
+            assert_eq!(UtcTime::from_ole_date(ole), None, "{}", ole);
+        }
+        assert_eq!(utc("0099-12-31T23:59:59.999Z").to_ole_date(), None);
+        assert_eq!(civil(10000, 1, 1, (0, 0, 0, 0)).to_ole_date(), None);
+    }
+}
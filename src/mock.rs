@@ -0,0 +1,198 @@
+//! Override the time returned by [`utcnow()`](crate::utcnow) for testing
+//!
+//! This module is only available with the feature `"mock"`.
+//!
+//! While an override is active, [`utcnow()`](crate::utcnow) and [`UtcTime::now()`] ask the installed [`Clock`]
+//! instead of the operating system. This works for every call in the process, including calls
+//! in your dependencies, without touching any call sites.
+//!
+//! A thread-local override takes precedence over the global override.
+//! Use thread-local overrides in unit tests, because the test harness runs multiple tests in parallel.
+//! [`SystemClock`](crate::SystemClock) always queries the operating system, and ignores any overrides.
+//!
+//! An override is removed when the returned guard is dropped, and the previous clock is restored.
+//! Drop guards in the reverse order of their creation.
+//!
+//! Please note that the installed clock must not call [`utcnow()`](crate::utcnow) itself,
+//! or you will end up in an infinite recursion.
+//!
+//! # Example
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use std::time::Duration;
+//! # use utcnow::{ManualClock, UtcTime, utcnow};
+//! let clock = Arc::new(ManualClock::new(UtcTime::EPOCH));
+//! let guard = utcnow::mock::set_global(Arc::clone(&clock));
+//! assert_eq!(utcnow().unwrap(), UtcTime::EPOCH);
+//!
+//! clock.advance(Duration::from_secs(3600));
+//! let handle = std::thread::spawn(|| utcnow().unwrap());
+//! assert_eq!(handle.join().unwrap().as_secs(), 3600);
+//!
+//! drop(guard);
+//! assert!(utcnow().unwrap().as_secs() > 1_658_711_810);
+//! ```
+
+use core::fmt;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::lazy::LazyBox;
+use crate::{Clock, Result, UtcTime};
+
+type GlobalClock = Arc<dyn Clock + Send + Sync>;
+type LocalClock = Rc<dyn Clock>;
+
+static GLOBAL: LazyBox<RwLock<Option<GlobalClock>>> = LazyBox::new();
+
+/// Lets [`utcnow()`] skip locking [`GLOBAL`] if there is no global override
+static GLOBAL_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+std::thread_local! {
+    static LOCAL: RefCell<Option<LocalClock>> = RefCell::new(None);
+}
+
+/// Override the clock of the whole process until the returned guard is dropped
+///
+/// # Example
+///
+/// ```
+/// # use utcnow::{FixedClock, UtcTime, utcnow};
+/// let _guard = utcnow::mock::set_global(FixedClock(UtcTime::EPOCH));
+/// assert_eq!(utcnow().unwrap(), UtcTime::EPOCH);
+/// ```
+pub fn set_global(clock: impl Clock + Send + Sync + 'static) -> GlobalGuard {
+    GlobalGuard {
+        previous: replace_global(Some(Arc::new(clock))),
+    }
+}
+
+/// Override the clock of the current thread until the returned guard is dropped
+///
+/// # Example
+///
+/// ```
+/// # use utcnow::{FixedClock, UtcTime, utcnow};
+/// let guard = utcnow::mock::set_thread_local(FixedClock(UtcTime::EPOCH));
+/// assert_eq!(utcnow().unwrap(), UtcTime::EPOCH);
+///
+/// // Other threads are not affected.
+/// let handle = std::thread::spawn(|| utcnow().unwrap());
+/// assert_ne!(handle.join().unwrap(), UtcTime::EPOCH);
+/// ```
+pub fn set_thread_local(clock: impl Clock + 'static) -> ThreadLocalGuard {
+    ThreadLocalGuard {
+        previous: replace_local(Some(Rc::new(clock))),
+    }
+}
+
+/// Restores the previous global clock when dropped
+///
+/// Returned by [`set_global()`].
+#[must_use = "the override is removed when the guard is dropped"]
+pub struct GlobalGuard {
+    previous: Option<GlobalClock>,
+}
+
+impl Drop for GlobalGuard {
+    fn drop(&mut self) {
+        drop(replace_global(self.previous.take()));
+    }
+}
+
+impl fmt::Debug for GlobalGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GlobalGuard").finish()
+    }
+}
+
+/// Restores the previous clock of the current thread when dropped
+///
+/// Returned by [`set_thread_local()`].
+#[must_use = "the override is removed when the guard is dropped"]
+pub struct ThreadLocalGuard {
+    // `Rc` makes the guard `!Send`, so it is dropped in the same thread it was created in.
+    previous: Option<LocalClock>,
+}
+
+impl Drop for ThreadLocalGuard {
+    fn drop(&mut self) {
+        drop(replace_local(self.previous.take()));
+    }
+}
+
+impl fmt::Debug for ThreadLocalGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadLocalGuard").finish()
+    }
+}
+
+fn global() -> &'static RwLock<Option<GlobalClock>> {
+    GLOBAL.get_or_init(|| RwLock::new(None))
+}
+
+fn replace_global(clock: Option<GlobalClock>) -> Option<GlobalClock> {
+    let mut global = global().write().unwrap_or_else(PoisonError::into_inner);
+    GLOBAL_ACTIVE.store(clock.is_some(), Ordering::Release);
+    core::mem::replace(&mut *global, clock)
+}
+
+fn replace_local(clock: Option<LocalClock>) -> Option<LocalClock> {
+    // If the thread is shutting down, there is nothing left to restore.
+    LOCAL.try_with(|local| local.replace(clock)).unwrap_or(None)
+}
+
+/// Query the overriding clock, if any
+pub(crate) fn utcnow() -> Option<Result<UtcTime>> {
+    // Clone the clocks, so we don't hold a lock or borrow while calling into user code.
+    let local = LOCAL
+        .try_with(|local| local.borrow().clone())
+        .unwrap_or(None);
+    if let Some(clock) = local {
+        return Some(clock.now());
+    }
+
+    if GLOBAL_ACTIVE.load(Ordering::Acquire) {
+        let global = global()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        if let Some(clock) = global {
+            return Some(clock.now());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use std::rc::Rc;
+
+    use super::set_thread_local;
+    use crate::{Clock, FixedClock, ManualClock, SystemClock, UtcTime, utcnow};
+
+    #[test]
+    fn test_thread_local() {
+        let real = utcnow().unwrap();
+
+        let clock = Rc::new(ManualClock::new(UtcTime::EPOCH));
+        let outer = set_thread_local(Rc::clone(&clock));
+        assert_eq!(utcnow().unwrap(), UtcTime::EPOCH);
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(UtcTime::now().unwrap().as_secs(), 60);
+        assert!(SystemClock.now().unwrap() >= real);
+
+        let inner = set_thread_local(FixedClock(UtcTime::MAX));
+        assert_eq!(utcnow().unwrap(), UtcTime::MAX);
+        drop(inner);
+        assert_eq!(utcnow().unwrap().as_secs(), 60);
+
+        drop(outer);
+        assert!(utcnow().unwrap() >= real);
+    }
+}
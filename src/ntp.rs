@@ -0,0 +1,364 @@
+use core::convert::TryFrom;
+use core::time::Duration;
+
+use crate::UtcTime;
+
+/// Seconds between 1900-01-01 (NTP epoch) and 1970-01-01 (Unix epoch)
+pub(crate) const NTP_TO_UNIX: i64 = 2_208_988_800;
+
+const ERA_SECS: i128 = 1 << 32;
+
+/// Convert a binary fraction with `bits` bits to nanoseconds, rounding down
+#[allow(clippy::cast_possible_truncation)] // the result is less than `1_000_000_000`
+const fn frac_to_nanos(frac: u64, bits: u32) -> u32 {
+    ((frac * 1_000_000_000) >> bits) as u32
+}
+
+/// Convert nanoseconds to a binary fraction with `bits` bits, rounding up
+///
+/// The result can be `1 << bits` for `bits < 30`.
+const fn nanos_to_frac(nanos: u32, bits: u32) -> u64 {
+    (((nanos as u64) << bits) + 999_999_999) / 1_000_000_000
+}
+
+/// An NTP timestamp: 32 bit seconds and 32 bit fraction since 1900-01-01T00:00:00 UTC
+///
+/// The timestamp does not contain the era number, so every 2<sup>32</sup> seconds (about 136 years)
+/// the value rolls over, the next time on 2036-02-07T06:28:16 UTC.
+/// Use [`NtpTimestamp64::to_utc()`] with a pivot time to resolve the era.
+///
+/// ## Rounding
+///
+/// The fraction has a resolution of about 233 ps, which is finer than the nanoseconds of [`UtcTime`].
+/// Converting to [`UtcTime`] rounds down to the previous nanosecond, like all conversions in this library.
+/// Converting from [`UtcTime`] rounds up to the next fraction,
+/// so `UtcTime` → `NtpTimestamp64` → `UtcTime` is lossless in the same era.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NtpTimestamp64 {
+    secs: u32,
+    frac: u32,
+}
+
+impl NtpTimestamp64 {
+    /// Create a timestamp from its seconds and fraction in units of 2<sup>-32</sup> seconds
+    #[must_use]
+    #[inline]
+    pub const fn new(secs: u32, frac: u32) -> Self {
+        Self { secs, frac }
+    }
+
+    /// Create a timestamp from its wire representation, seconds in the high 32 bits
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self::new((bits >> 32) as u32, bits as u32)
+    }
+
+    /// The wire representation, seconds in the high 32 bits
+    #[must_use]
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        ((self.secs as u64) << 32) | (self.frac as u64)
+    }
+
+    /// Seconds since the start of the era
+    #[must_use]
+    #[inline]
+    pub const fn secs(self) -> u32 {
+        self.secs
+    }
+
+    /// Fractional seconds in units of 2<sup>-32</sup> seconds
+    #[must_use]
+    #[inline]
+    pub const fn fraction(self) -> u32 {
+        self.frac
+    }
+
+    /// Convert a [`UtcTime`], discarding the era number
+    ///
+    /// The era of `time` is returned by [`NtpTimestamp64::era_of()`].
+    /// See the [type documentation](NtpTimestamp64) for the rounding of the fraction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{NtpTimestamp64, UtcTime};
+    /// let time = UtcTime::parse_rfc3339("2036-02-07T06:28:16.5Z").unwrap();
+    /// let ntp = NtpTimestamp64::from_utc(time);
+    /// assert_eq!(ntp, NtpTimestamp64::new(0, 1 << 31));
+    /// assert_eq!(NtpTimestamp64::era_of(time), 1);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_utc(time: UtcTime) -> Self {
+        let frac = nanos_to_frac(time.subsec_nanos(), 32);
+        let secs = i128::from(time.as_secs()) + i128::from(NTP_TO_UNIX);
+        // wraps around every era
+        Self::new(secs as u32, frac as u32)
+    }
+
+    /// The NTP era `time` is in, era 0 started 1900-01-01, era 1 starts 2036-02-07
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn era_of(time: UtcTime) -> i64 {
+        let secs = i128::from(time.as_secs()) + i128::from(NTP_TO_UNIX);
+        // an `i64` number of seconds spans less than 2^32 eras
+        secs.div_euclid(ERA_SECS) as i64
+    }
+
+    /// Convert to [`UtcTime`], choosing the era that is closest to `pivot`
+    ///
+    /// The result is less than 2<sup>31</sup> seconds (about 68 years) apart from `pivot`.
+    /// Usually you will use the current time, or the time the packet was received, as the pivot.
+    /// Returns `None` if the result is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{NtpTimestamp64, UtcTime};
+    /// let ntp = NtpTimestamp64::new(10, 0);
+    /// let pivot = UtcTime::parse_rfc3339("2030-01-01T00:00:00Z").unwrap();
+    /// assert_eq!(
+    ///     ntp.to_utc(pivot).unwrap().rfc3339().to_string(),
+    ///     "2036-02-07T06:28:26Z",
+    /// );
+    /// let pivot = UtcTime::parse_rfc3339("1950-01-01T00:00:00Z").unwrap();
+    /// assert_eq!(
+    ///     ntp.to_utc(pivot).unwrap().rfc3339().to_string(),
+    ///     "1900-01-01T00:00:10Z",
+    /// );
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_utc(self, pivot: UtcTime) -> Option<UtcTime> {
+        let pivot = i128::from(pivot.as_secs()) + i128::from(NTP_TO_UNIX);
+        let era = (pivot - i128::from(self.secs) + ERA_SECS / 2).div_euclid(ERA_SECS);
+        // `era` is at most 2^31 + 1, see `era_of()`
+        self.to_utc_in_era(era as i64)
+    }
+
+    /// Convert to [`UtcTime`] with a known era
+    ///
+    /// Returns `None` if the result is out of range.
+    #[must_use]
+    pub fn to_utc_in_era(self, era: i64) -> Option<UtcTime> {
+        let secs = i128::from(era) * ERA_SECS + i128::from(self.secs) - i128::from(NTP_TO_UNIX);
+        let nanos = frac_to_nanos(self.frac.into(), 32);
+        UtcTime::new(i64::try_from(secs).ok()?, nanos)
+    }
+}
+
+impl From<u64> for NtpTimestamp64 {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self::from_bits(value)
+    }
+}
+
+impl From<NtpTimestamp64> for u64 {
+    #[inline]
+    fn from(value: NtpTimestamp64) -> Self {
+        value.to_bits()
+    }
+}
+
+/// An NTP short format value: 16 bit seconds and 16 bit fraction
+///
+/// The format is used for spans of time, e.g. the root delay and the root dispersion in an NTP packet.
+/// It can represent up to about 18 hours with a resolution of about 15 µs.
+///
+/// ## Rounding
+///
+/// Converting to [`Duration`] rounds down to the previous nanosecond.
+/// Converting from [`Duration`] rounds up to the next fraction,
+/// so `NtpShort32` → [`Duration`] → `NtpShort32` is lossless.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NtpShort32 {
+    secs: u16,
+    frac: u16,
+}
+
+impl NtpShort32 {
+    /// Create a value from its seconds and fraction in units of 2<sup>-16</sup> seconds
+    #[must_use]
+    #[inline]
+    pub const fn new(secs: u16, frac: u16) -> Self {
+        Self { secs, frac }
+    }
+
+    /// Create a value from its wire representation, seconds in the high 16 bits
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self::new((bits >> 16) as u16, bits as u16)
+    }
+
+    /// The wire representation, seconds in the high 16 bits
+    #[must_use]
+    #[inline]
+    pub const fn to_bits(self) -> u32 {
+        ((self.secs as u32) << 16) | (self.frac as u32)
+    }
+
+    /// Whole seconds
+    #[must_use]
+    #[inline]
+    pub const fn secs(self) -> u16 {
+        self.secs
+    }
+
+    /// Fractional seconds in units of 2<sup>-16</sup> seconds
+    #[must_use]
+    #[inline]
+    pub const fn fraction(self) -> u16 {
+        self.frac
+    }
+
+    /// Convert a [`Duration`]
+    ///
+    /// Returns `None` if the value does not fit, i.e. if it would be 65536 seconds or more after rounding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// # use utcnow::NtpShort32;
+    /// let short = NtpShort32::from_duration(Duration::from_millis(1500)).unwrap();
+    /// assert_eq!(short, NtpShort32::new(1, 0x8000));
+    /// assert_eq!(short.to_duration(), Duration::from_millis(1500));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_duration(value: Duration) -> Option<Self> {
+        let frac = nanos_to_frac(value.subsec_nanos(), 16);
+        let secs = value.as_secs().checked_add(frac >> 16)?;
+        Some(Self::new(u16::try_from(secs).ok()?, frac as u16))
+    }
+
+    /// Convert to [`Duration`]
+    #[must_use]
+    pub fn to_duration(self) -> Duration {
+        Duration::new(self.secs.into(), frac_to_nanos(self.frac.into(), 16))
+    }
+}
+
+impl From<u32> for NtpShort32 {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self::from_bits(value)
+    }
+}
+
+impl From<NtpShort32> for u32 {
+    #[inline]
+    fn from(value: NtpShort32) -> Self {
+        value.to_bits()
+    }
+}
+
+impl From<NtpShort32> for Duration {
+    #[inline]
+    fn from(value: NtpShort32) -> Self {
+        value.to_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use crate::{NtpShort32, NtpTimestamp64, UtcTime};
+
+    fn utc(s: &str) -> UtcTime {
+        UtcTime::parse_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn test_timestamp64() {
+        let epoch = NtpTimestamp64::from_utc(UtcTime::EPOCH);
+        assert_eq!(epoch, NtpTimestamp64::new(2_208_988_800, 0));
+        assert_eq!(epoch.to_utc(UtcTime::EPOCH), Some(UtcTime::EPOCH));
+        assert_eq!(NtpTimestamp64::era_of(UtcTime::EPOCH), 0);
+        assert_eq!(NtpTimestamp64::era_of(utc("1899-12-31T23:59:59Z")), -1);
+        assert_eq!(NtpTimestamp64::era_of(UtcTime::MIN), i64::from(i32::MIN));
+        assert_eq!(NtpTimestamp64::era_of(UtcTime::MAX), 1 << 31);
+
+        let bits = 0xE0A1_B2C3_8000_0000_u64;
+        let ntp = NtpTimestamp64::from_bits(bits);
+        assert_eq!(u64::from(ntp), bits);
+        assert_eq!((ntp.secs(), ntp.fraction()), (0xE0A1_B2C3, 0x8000_0000));
+        assert_eq!(ntp.to_utc_in_era(0).unwrap().subsec_nanos(), 500_000_000);
+    }
+
+    #[test]
+    fn test_era_rollover() {
+        let before = utc("2036-02-07T06:28:15.999999999Z");
+        let after = utc("2036-02-07T06:28:16Z");
+        assert_eq!(NtpTimestamp64::from_utc(before).secs(), u32::MAX);
+        assert_eq!(NtpTimestamp64::from_utc(after), NtpTimestamp64::new(0, 0));
+        assert_eq!(NtpTimestamp64::era_of(before), 0);
+        assert_eq!(NtpTimestamp64::era_of(after), 1);
+
+        for &pivot in &[
+            before,
+            after,
+            utc("2000-01-01T00:00:00Z"),
+            utc("2100-01-01T00:00:00Z"),
+        ] {
+            for &time in &[before, after] {
+                assert_eq!(NtpTimestamp64::from_utc(time).to_utc(pivot), Some(time));
+            }
+        }
+        let ntp = NtpTimestamp64::new(0, 0);
+        assert_eq!(ntp.to_utc_in_era(0), Some(utc("1900-01-01T00:00:00Z")));
+        assert_eq!(ntp.to_utc_in_era(1), Some(after));
+        assert_eq!(ntp.to_utc_in_era(1 << 32), None);
+        assert_eq!(ntp.to_utc_in_era(-1 << 32), None);
+        assert_eq!(NtpTimestamp64::new(u32::MAX, 0).to_utc(UtcTime::MAX), None);
+    }
+
+    #[test]
+    fn test_rounding() {
+        for &nanos in &[0, 1, 2, 3, 4, 5, 233, 499_999_999, 500_000_000, 999_999_999] {
+            let time = UtcTime::new(1_700_000_000, nanos).unwrap();
+            let ntp = NtpTimestamp64::from_utc(time);
+            assert_eq!(ntp.to_utc(time), Some(time), "{}", nanos);
+        }
+        let ntp = NtpTimestamp64::new(0, u32::MAX);
+        assert_eq!(ntp.to_utc_in_era(1).unwrap().subsec_nanos(), 999_999_999);
+        let ntp = NtpTimestamp64::new(0, 1);
+        assert_eq!(ntp.to_utc_in_era(1).unwrap().subsec_nanos(), 0);
+    }
+
+    #[test]
+    fn test_short32() {
+        assert_eq!(
+            NtpShort32::from_bits(0x0001_8000),
+            NtpShort32::new(1, 0x8000)
+        );
+        assert_eq!(u32::from(NtpShort32::new(1, 0x8000)), 0x0001_8000);
+        for bits in (0..=u32::MAX).step_by(4099) {
+            let short = NtpShort32::from(bits);
+            assert_eq!(
+                NtpShort32::from_duration(Duration::from(short)),
+                Some(short)
+            );
+        }
+        assert_eq!(
+            NtpShort32::from_duration(Duration::new(0, 999_999_999)),
+            Some(NtpShort32::new(1, 0)),
+        );
+        assert_eq!(
+            NtpShort32::from_duration(Duration::new(65535, 999_999_999)),
+            None,
+        );
+        assert_eq!(NtpShort32::from_duration(Duration::from_secs(65536)), None);
+        assert_eq!(
+            NtpShort32::new(u16::MAX, u16::MAX).to_duration(),
+            Duration::new(65535, 999_984_741),
+        );
+    }
+}
@@ -1,26 +1,124 @@
+//! Used if no native implementation exists for the target platform
+//!
+//! With the feature `"custom"`, the function registered with [`register_custom_utcnow!`] is called.
+//! Otherwise, with the feature `"std"`, the time is read from [`SystemTime::now()`](std::time::SystemTime::now).
+//! Otherwise [`utcnow()`] always fails.
+
 use core::fmt;
+#[cfg(feature = "custom")]
+use core::num::NonZeroU32;
+use core::time::Duration;
 
-use crate::{Error, Result, UtcTime};
+use crate::{Backend, ClockError, ClockSource, Error, ErrorKind, RawClockReading, Result, UtcTime};
 
-pub(crate) const IMPLEMENTED: bool = false;
+pub(crate) const IMPLEMENTED: bool = cfg!(any(feature = "custom", feature = "std"));
 pub(crate) const INFALLIBLE: bool = false;
 
+pub(crate) const BACKEND: Backend = Backend::Fallback;
+#[cfg(feature = "custom")]
+pub(crate) const CLOCK_ID: &str = "custom";
+#[cfg(all(feature = "std", not(feature = "custom")))]
+pub(crate) const CLOCK_ID: &str = "SystemTime::now";
+#[cfg(not(any(feature = "std", feature = "custom")))]
+pub(crate) const CLOCK_ID: &str = "none";
+
+pub(crate) fn resolution() -> Option<Duration> {
+    None
+}
+
+#[cfg(feature = "custom")]
 #[inline]
 pub(crate) fn utcnow() -> Result<UtcTime> {
-    Err(Error(OsError))
+    crate::custom::utcnow().map_err(|code| Error(OsError(Reason::Custom(code))))
+}
+
+#[cfg(all(feature = "std", not(feature = "custom")))]
+pub(crate) fn utcnow() -> Result<UtcTime> {
+    use std::time::SystemTime;
+
+    let now = SystemTime::now();
+    let result = match UtcTime::from_system_time(now) {
+        Some(now) => Some(now),
+        None => match SystemTime::UNIX_EPOCH.duration_since(now) {
+            Ok(before_epoch) => UtcTime::EPOCH.checked_sub_duration(before_epoch),
+            Err(_) => None,
+        },
+    };
+    result.ok_or(Error(OsError(Reason::OutOfRange)))
+}
+
+#[cfg(not(any(feature = "std", feature = "custom")))]
+#[inline]
+pub(crate) fn utcnow() -> Result<UtcTime> {
+    Err(Error(OsError(Reason::NotImplemented)))
+}
+
+pub(crate) const fn clock_implemented(source: ClockSource) -> bool {
+    matches!(source, ClockSource::Realtime) && IMPLEMENTED
+}
+
+pub(crate) const fn clock_infallible(_: ClockSource) -> bool {
+    false
+}
+
+pub(crate) fn now_from(source: ClockSource) -> core::result::Result<RawClockReading, ClockError> {
+    match source {
+        ClockSource::Realtime => match utcnow() {
+            Ok(now) => Ok(unsafe {
+                RawClockReading::new_unchecked(source, now.as_secs(), now.subsec_nanos())
+            }),
+            Err(err) => Err(ClockError::new(source, err.kind(), err.raw_os_error())),
+        },
+        _ => Err(ClockError::unsupported(source)),
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct OsError;
+pub struct OsError(Reason);
 
-impl fmt::Display for OsError {
+#[derive(Debug, Clone, Copy)]
+enum Reason {
+    #[cfg(not(any(feature = "std", feature = "custom")))]
+    NotImplemented,
+    #[cfg(all(feature = "std", not(feature = "custom")))]
+    OutOfRange,
+    #[cfg(feature = "custom")]
+    Custom(NonZeroU32),
+}
+
+impl OsError {
     #[inline]
+    pub(crate) fn raw_os_error(self) -> Option<i32> {
+        None
+    }
+
+    #[inline]
+    pub(crate) fn kind(self) -> ErrorKind {
+        match self.0 {
+            #[cfg(not(any(feature = "std", feature = "custom")))]
+            Reason::NotImplemented => ErrorKind::NotImplemented,
+            #[cfg(all(feature = "std", not(feature = "custom")))]
+            Reason::OutOfRange => ErrorKind::Other,
+            #[cfg(feature = "custom")]
+            Reason::Custom(_) => ErrorKind::Other,
+        }
+    }
+}
+
+impl fmt::Display for OsError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("platform is not implemented")
+        match self.0 {
+            #[cfg(not(any(feature = "std", feature = "custom")))]
+            Reason::NotImplemented => f.write_str("platform is not implemented"),
+            #[cfg(all(feature = "std", not(feature = "custom")))]
+            Reason::OutOfRange => f.write_str("system time is out of range"),
+            #[cfg(feature = "custom")]
+            Reason::Custom(code) => write!(f, "custom time source failed with error code {}", code),
+        }
     }
 }
 
-#[cfg(not(feature = "fallback"))]
+#[cfg(not(any(feature = "fallback", feature = "custom")))]
 compile_error!(
     "Target platform is not implemented. Please file a bug report to https://github.com/Kijewski/utcnow/",
 );
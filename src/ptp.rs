@@ -0,0 +1,155 @@
+use core::convert::TryFrom;
+
+use crate::u30::U30;
+use crate::{TaiTime, UtcTime};
+
+const MAX_SECS: u64 = (1 << 48) - 1;
+
+/// A PTP (IEEE 1588) timestamp: 48 bit seconds and 32 bit nanoseconds
+///
+/// With the default PTP timescale the timestamp counts seconds since 1970-01-01T00:00:00 TAI,
+/// i.e. it uses the same scale as [`TaiTime`], and does not skip or repeat during leap seconds.
+/// [`PtpTimestamp::from_utc()`] and [`PtpTimestamp::to_utc()`] use the
+/// [installed](crate::LeapSecondTable::install) leap second table to convert to and from UTC.
+/// All conversions are lossless if the value is in range.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PtpTimestamp {
+    secs: u64,
+    nanos: U30,
+}
+
+impl PtpTimestamp {
+    /// Create a new timestamp
+    ///
+    /// Returns `None` if `secs` does not fit into 48 bits, or if `nanos` is not less than 1 000 000 000.
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn new(secs: u64, nanos: u32) -> Option<Self> {
+        if secs > MAX_SECS || nanos >= 1_000_000_000 {
+            return None;
+        }
+        Some(Self {
+            secs,
+            nanos: unsafe { U30::new_unchecked(nanos) },
+        })
+    }
+
+    /// Parse the 10 byte big-endian wire representation
+    ///
+    /// Returns `None` if the nanoseconds are out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::PtpTimestamp;
+    /// let bytes = [0, 0, 0x65, 0x53, 0xF1, 0x00, 0x1D, 0xCD, 0x65, 0x00];
+    /// let ptp = PtpTimestamp::from_bytes(bytes).unwrap();
+    /// assert_eq!((ptp.as_secs(), ptp.subsec_nanos()), (1_700_000_000, 500_000_000));
+    /// assert_eq!(ptp.to_bytes(), bytes);
+    /// ```
+    #[must_use]
+    pub fn from_bytes(bytes: [u8; 10]) -> Option<Self> {
+        let [s0, s1, s2, s3, s4, s5, n0, n1, n2, n3] = bytes;
+        let secs = u64::from_be_bytes([0, 0, s0, s1, s2, s3, s4, s5]);
+        Self::new(secs, u32::from_be_bytes([n0, n1, n2, n3]))
+    }
+
+    /// The 10 byte big-endian wire representation
+    #[must_use]
+    pub fn to_bytes(self) -> [u8; 10] {
+        let [_, _, s0, s1, s2, s3, s4, s5] = self.secs.to_be_bytes();
+        let [n0, n1, n2, n3] = self.nanos.get().to_be_bytes();
+        [s0, s1, s2, s3, s4, s5, n0, n1, n2, n3]
+    }
+
+    /// Whole seconds since the PTP epoch
+    #[must_use]
+    #[inline]
+    pub const fn as_secs(self) -> u64 {
+        self.secs
+    }
+
+    /// Fractional number of nanoseconds
+    #[must_use]
+    #[inline]
+    #[const_fn::const_fn("1.56")]
+    pub fn subsec_nanos(self) -> u32 {
+        self.nanos.get()
+    }
+
+    /// Convert a [`TaiTime`]
+    ///
+    /// Returns `None` if the time is before 1970-01-01 TAI, or too far in the future for 48 bits.
+    #[must_use]
+    pub fn from_tai(time: TaiTime) -> Option<Self> {
+        Self::new(u64::try_from(time.as_secs()).ok()?, time.subsec_nanos())
+    }
+
+    /// Convert to [`TaiTime`]
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)] // `secs` has only 48 bits
+    pub fn to_tai(self) -> TaiTime {
+        unsafe { TaiTime::new_unchecked(self.secs as i64, self.nanos.get()) }
+    }
+
+    /// Convert a [`UtcTime`], using the [installed](crate::LeapSecondTable::install) leap second table
+    ///
+    /// Returns `None` if the time is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{PtpTimestamp, UtcTime};
+    /// let utc = UtcTime::parse_rfc3339("2017-01-01T00:00:00Z").unwrap();
+    /// let ptp = PtpTimestamp::from_utc(utc).unwrap();
+    /// assert_eq!(ptp.as_secs(), 1_483_228_800 + 37);
+    /// assert_eq!(ptp.to_utc(), Some(utc));
+    /// ```
+    #[must_use]
+    pub fn from_utc(time: UtcTime) -> Option<Self> {
+        Self::from_tai(time.to_tai()?)
+    }
+
+    /// Convert to [`UtcTime`], using the [installed](crate::LeapSecondTable::install) leap second table
+    ///
+    /// A timestamp in a positive leap second is folded into the next second,
+    /// see [`TaiTime::to_utc()`].
+    #[must_use]
+    pub fn to_utc(self) -> Option<UtcTime> {
+        self.to_tai().to_utc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PtpTimestamp, TaiTime, UtcTime};
+
+    #[test]
+    fn test_ptp() {
+        assert_eq!(PtpTimestamp::new(1 << 48, 0), None);
+        assert_eq!(PtpTimestamp::new(0, 1_000_000_000), None);
+        let max = PtpTimestamp::new((1 << 48) - 1, 999_999_999).unwrap();
+        assert_eq!(max.to_bytes(), [
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3B, 0x9A, 0xC9, 0xFF
+        ]);
+        assert_eq!(PtpTimestamp::from_bytes(max.to_bytes()), Some(max));
+        assert_eq!(PtpTimestamp::from_tai(max.to_tai()), Some(max));
+        assert_eq!(PtpTimestamp::from_bytes([0xFF; 10]), None);
+
+        assert_eq!(PtpTimestamp::from_tai(TaiTime::new(-1, 0).unwrap()), None);
+        assert_eq!(
+            PtpTimestamp::from_tai(TaiTime::new(1 << 48, 0).unwrap()),
+            None
+        );
+        assert_eq!(
+            PtpTimestamp::from_utc(UtcTime::EPOCH).unwrap().as_secs(),
+            10
+        );
+        assert_eq!(PtpTimestamp::from_utc(UtcTime::new(-11, 0).unwrap()), None);
+
+        let time = UtcTime::parse_rfc3339("2024-02-29T12:34:56.789Z").unwrap();
+        let ptp = PtpTimestamp::from_utc(time).unwrap();
+        assert_eq!(ptp.subsec_nanos(), 789_000_000);
+        assert_eq!(ptp.to_utc(), Some(time));
+    }
+}
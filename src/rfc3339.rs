@@ -0,0 +1,526 @@
+use core::fmt;
+
+use crate::{CivilDateTime, ConversionError, ConversionErrorKind, UtcTime};
+
+/// Number of fractional second digits to write in [`UtcTime::rfc3339_with()`]
+///
+/// Fractional seconds are truncated, never rounded, so the formatted timestamp never lies in the future.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Precision {
+    /// Whole seconds, e.g. `2022-08-03T16:54:53Z`
+    Seconds,
+    /// Three digits, e.g. `2022-08-03T16:54:53.895Z`
+    Millis,
+    /// Six digits, e.g. `2022-08-03T16:54:53.895531Z`
+    Micros,
+    /// Nine digits, e.g. `2022-08-03T16:54:53.895531827Z`
+    Nanos,
+    /// As many digits as needed, trailing zeros are removed, e.g. `2022-08-03T16:54:53.8955318Z`
+    Auto,
+}
+
+impl Default for Precision {
+    #[inline]
+    fn default() -> Self {
+        Precision::Auto
+    }
+}
+
+/// A [`UtcTime`] that is [displayed](fmt::Display) as an [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) timestamp
+///
+/// Use [`UtcTime::rfc3339()`] or [`UtcTime::rfc3339_with()`] to get an instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rfc3339 {
+    time: UtcTime,
+    precision: Precision,
+}
+
+impl UtcTime {
+    /// Format the timestamp as an [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) string
+    ///
+    /// Trailing zeros of the fractional seconds are omitted. Use [`UtcTime::rfc3339_with()`] to
+    /// select a fixed number of digits. The result does not allocate, so it works in `no_std`.
+    ///
+    /// Years before 0000 or after 9999 cannot be represented in RFC 3339.
+    /// They are written in the expanded ISO 8601 format with an explicit sign, e.g. `+10000-01-01T00:00:00Z`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let timestamp = UtcTime::new(1_659_545_693, 895_531_800).unwrap();
+    /// assert_eq!(timestamp.rfc3339().to_string(), "2022-08-03T16:54:53.8955318Z");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn rfc3339(self) -> Rfc3339 {
+        self.rfc3339_with(Precision::Auto)
+    }
+
+    /// Format the timestamp as an [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) string
+    ///
+    /// See [`UtcTime::rfc3339()`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{Precision, UtcTime};
+    /// let timestamp = UtcTime::new(1_659_545_693, 895_531_827).unwrap();
+    /// assert_eq!(
+    ///     timestamp.rfc3339_with(Precision::Millis).to_string(),
+    ///     "2022-08-03T16:54:53.895Z",
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn rfc3339_with(self, precision: Precision) -> Rfc3339 {
+        Rfc3339 {
+            time: self,
+            precision,
+        }
+    }
+
+    /// Parse an [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) timestamp
+    ///
+    /// The separators `T` and `Z` may be upper or lower case, and the date and time may be separated
+    /// by a space instead of `T`. Numeric offsets like `+02:00` are converted to UTC.
+    /// Fractional seconds with more than nine digits are truncated.
+    /// A leap second (`:60`) is treated as the first second of the next minute.
+    ///
+    /// Years outside of 0000 to 9999 are accepted in the expanded format written by [`UtcTime::rfc3339()`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is malformed, or if the timestamp is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let expected = UtcTime::new(1_714_566_896, 789_000_000).unwrap();
+    /// assert_eq!(UtcTime::parse_rfc3339("2024-05-01T12:34:56.789Z"), Ok(expected));
+    /// assert_eq!(UtcTime::parse_rfc3339("2024-05-01t14:34:56.789+02:00"), Ok(expected));
+    /// assert_eq!(UtcTime::parse_rfc3339("2024-05-01 12:04:56.789-00:30"), Ok(expected));
+    /// ```
+    #[const_fn::const_fn("1.56")]
+    pub fn parse_rfc3339(value: &str) -> Result<Self, ConversionError> {
+        parse(value.as_bytes())
+    }
+
+    /// Parse an [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) timestamp in a constant context
+    ///
+    /// Accepts the same input as [`UtcTime::parse_rfc3339()`].
+    /// Usually you will want to use the macro [`utc!`](crate::utc!) instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input is malformed, or if the timestamp is out of range.
+    /// If evaluated in a constant context, the panic is a compile-time error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// const RELEASE: UtcTime = UtcTime::parse_const("2022-08-03T17:14:53.895531827Z");
+    /// assert_eq!(RELEASE, UtcTime::new(1_659_546_893, 895_531_827).unwrap());
+    /// ```
+    #[must_use]
+    #[track_caller]
+    #[const_fn::const_fn("1.57")]
+    pub fn parse_const(value: &str) -> Self {
+        match parse(value.as_bytes()) {
+            Ok(value) => value,
+            Err(err) => match err.kind() {
+                ConversionErrorKind::Empty => panic!("empty timestamp"),
+                ConversionErrorKind::InvalidCharacter { .. } => {
+                    panic!("malformed timestamp, expected RFC 3339")
+                },
+                ConversionErrorKind::TooManyDigits => panic!("too many digits in timestamp"),
+                ConversionErrorKind::OutOfRange => panic!("field out of range in timestamp"),
+                ConversionErrorKind::Negative | ConversionErrorKind::Overflow => {
+                    panic!("timestamp is out of range")
+                },
+            },
+        }
+    }
+}
+
+impl fmt::Display for Rfc3339 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let civil = self.time.to_civil();
+        if (0..=9999).contains(&civil.year) {
+            write!(f, "{:04}", civil.year)?;
+        } else {
+            write!(f, "{:+05}", civil.year)?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}",
+            civil.month, civil.day, civil.hour, civil.minute, civil.second,
+        )?;
+
+        let nanos = civil.nanosecond;
+        match self.precision {
+            Precision::Seconds => {},
+            Precision::Millis => write!(f, ".{:03}", nanos / 1_000_000)?,
+            Precision::Micros => write!(f, ".{:06}", nanos / 1_000)?,
+            Precision::Nanos => write!(f, ".{:09}", nanos)?,
+            Precision::Auto if nanos == 0 => {},
+            Precision::Auto => {
+                let (mut value, mut width) = (nanos, 9);
+                while value % 10 == 0 {
+                    value /= 10;
+                    width -= 1;
+                }
+                write!(f, ".{:0width$}", value, width = width)?;
+            },
+        }
+        f.write_str("Z")
+    }
+}
+
+/// A [`UtcTime`] literal, parsed and validated at compile time
+///
+/// The input is an [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) timestamp,
+/// as accepted by [`UtcTime::parse_rfc3339()`]. The macro can be used in a constant context.
+/// Malformed input, or a timestamp that is out of range, is a compile-time error.
+///
+/// The macro needs Rust 1.57 or newer, because [`UtcTime::parse_const()`] is a `const fn` only since then.
+///
+/// # Example
+///
+/// ```
+/// use utcnow::{UtcTime, utc};
+///
+/// const RELEASE: UtcTime = utc!("2022-08-03T17:14:53.895531827Z");
+/// assert_eq!(RELEASE, UtcTime::new(1_659_546_893, 895_531_827).unwrap());
+/// assert_eq!(utc!("1969-12-31T23:59:59Z"), UtcTime::from_secs(-1));
+/// ```
+///
+/// ```compile_fail
+/// // February 30 does not exist
+/// let invalid = utcnow::utc!("2022-02-30T00:00:00Z");
+/// ```
+#[macro_export]
+macro_rules! utc {
+    ($value:expr $(,)?) => {{
+        const VALUE: $crate::UtcTime = $crate::UtcTime::parse_const($value);
+        VALUE
+    }};
+}
+
+#[allow(clippy::too_many_lines)]
+#[allow(clippy::cast_lossless)]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
+#[const_fn::const_fn("1.56")]
+const fn parse(s: &[u8]) -> Result<UtcTime, ConversionError> {
+    if s.is_empty() {
+        return Err(ConversionError::EMPTY);
+    }
+
+    // date-fullyear: 4 digits, or a sign followed by at least 4 digits
+    let (year, mut pos) = match s.first() {
+        Some(&sign) if sign == b'+' || sign == b'-' => {
+            let mut pos = 1;
+            let mut year: i64 = 0;
+            while pos < s.len() && s[pos].is_ascii_digit() {
+                year = year * 10 + (s[pos] - b'0') as i64;
+                if year > MAX_YEAR_DIGITS {
+                    return Err(ConversionError::TOO_MANY_DIGITS);
+                }
+                pos += 1;
+            }
+            if pos < 5 {
+                return Err(ConversionError::invalid_character(pos));
+            }
+            (if sign == b'-' { -year } else { year }, pos)
+        },
+        _ => (const_try!(digits(s, 0, 4)) as i64, 4),
+    };
+
+    // "-" date-month "-" date-mday
+    const_try!(expect(s, pos, b'-'));
+    let month = const_try!(digits(s, pos + 1, 2)) as u8;
+    const_try!(expect(s, pos + 3, b'-'));
+    let day = const_try!(digits(s, pos + 4, 2)) as u8;
+    pos += 6;
+
+    // "T" time-hour ":" time-minute ":" time-second
+    if !matches!(byte(s, pos), Some(b'T') | Some(b't') | Some(b' ')) {
+        return Err(ConversionError::invalid_character(pos));
+    }
+    let hour = const_try!(digits(s, pos + 1, 2)) as u8;
+    const_try!(expect(s, pos + 3, b':'));
+    let minute = const_try!(digits(s, pos + 4, 2)) as u8;
+    const_try!(expect(s, pos + 6, b':'));
+    let second = const_try!(digits(s, pos + 7, 2)) as u8;
+    pos += 9;
+
+    // [time-secfrac]
+    let mut nanosecond = 0;
+    if matches!(byte(s, pos), Some(b'.')) {
+        pos += 1;
+        let start = pos;
+        let mut factor = 100_000_000;
+        while pos < s.len() && s[pos].is_ascii_digit() {
+            nanosecond += (s[pos] - b'0') as u32 * factor;
+            factor /= 10;
+            pos += 1;
+        }
+        if pos == start {
+            return Err(ConversionError::invalid_character(pos));
+        }
+    }
+
+    // time-offset
+    let offset = match byte(s, pos) {
+        Some(b'Z') | Some(b'z') => {
+            pos += 1;
+            0
+        },
+        Some(sign) if sign == b'+' || sign == b'-' => {
+            let hours = const_try!(digits(s, pos + 1, 2)) as i64;
+            const_try!(expect(s, pos + 3, b':'));
+            let minutes = const_try!(digits(s, pos + 4, 2)) as i64;
+            if hours > 23 || minutes > 59 {
+                return Err(ConversionError::OUT_OF_RANGE);
+            }
+            pos += 6;
+            let offset = hours * 3600 + minutes * 60;
+            if sign == b'-' { -offset } else { offset }
+        },
+        _ => return Err(ConversionError::invalid_character(pos)),
+    };
+    if pos != s.len() {
+        return Err(ConversionError::invalid_character(pos));
+    }
+
+    // A leap second is folded into the next minute.
+    let (second, leap) = match second {
+        60 => (59, 1),
+        second => (second, 0),
+    };
+    let civil = match CivilDateTime::new(year, month, day, hour, minute, second, nanosecond) {
+        Some(civil) => civil,
+        None => return Err(ConversionError::OUT_OF_RANGE),
+    };
+    let time = match UtcTime::from_civil(civil) {
+        Some(time) => time,
+        None => return Err(ConversionError::OVERFLOW),
+    };
+    match time.secs.checked_add(leap - offset) {
+        Some(secs) => Ok(unsafe { UtcTime::new_unchecked(secs, nanosecond) }),
+        None => Err(ConversionError::OVERFLOW),
+    }
+}
+
+/// Years with more than 12 digits are out of range for any [`UtcTime`]
+const MAX_YEAR_DIGITS: i64 = 999_999_999_999;
+
+const fn byte(s: &[u8], pos: usize) -> Option<u8> {
+    if pos < s.len() { Some(s[pos]) } else { None }
+}
+
+const fn expect(s: &[u8], pos: usize, expected: u8) -> Result<(), ConversionError> {
+    match byte(s, pos) {
+        Some(c) if c == expected => Ok(()),
+        _ => Err(ConversionError::invalid_character(pos)),
+    }
+}
+
+/// Parse exactly `count` ASCII digits starting at `pos`
+const fn digits(s: &[u8], pos: usize, count: usize) -> Result<u32, ConversionError> {
+    let mut value = 0;
+    let mut idx = pos;
+    while idx < pos + count {
+        if idx >= s.len() || !s[idx].is_ascii_digit() {
+            return Err(ConversionError::invalid_character(idx));
+        }
+        value = value * 10 + (s[idx] - b'0') as u32;
+        idx += 1;
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ConversionErrorKind, Precision, UtcTime};
+
+    #[test]
+    fn test_format() {
+        let timestamp = UtcTime::new(1_659_545_693, 895_531_827).unwrap();
+        assert_eq!(
+            timestamp.rfc3339_with(Precision::Seconds).to_string(),
+            "2022-08-03T16:54:53Z",
+        );
+        assert_eq!(
+            timestamp.rfc3339_with(Precision::Micros).to_string(),
+            "2022-08-03T16:54:53.895531Z",
+        );
+        assert_eq!(
+            timestamp.rfc3339_with(Precision::Nanos).to_string(),
+            "2022-08-03T16:54:53.895531827Z",
+        );
+        assert_eq!(
+            timestamp.rfc3339().to_string(),
+            "2022-08-03T16:54:53.895531827Z",
+        );
+
+        let timestamp = UtcTime::new(-1, 500_000_000).unwrap();
+        assert_eq!(timestamp.rfc3339().to_string(), "1969-12-31T23:59:59.5Z");
+        assert_eq!(
+            timestamp.rfc3339_with(Precision::Nanos).to_string(),
+            "1969-12-31T23:59:59.500000000Z",
+        );
+        assert_eq!(UtcTime::EPOCH.rfc3339().to_string(), "1970-01-01T00:00:00Z");
+
+        assert_eq!(
+            UtcTime::MAX.rfc3339().to_string(),
+            "+292277026596-12-04T15:30:07.999999999Z",
+        );
+        assert_eq!(
+            UtcTime::MIN.rfc3339().to_string(),
+            "-292277022657-01-27T08:29:52Z",
+        );
+        assert_eq!(
+            UtcTime::new(-62_167_219_200 - 1, 0)
+                .unwrap()
+                .rfc3339()
+                .to_string(),
+            "-0001-12-31T23:59:59Z",
+        );
+        assert_eq!(
+            UtcTime::new(253_402_300_800, 0)
+                .unwrap()
+                .rfc3339()
+                .to_string(),
+            "+10000-01-01T00:00:00Z",
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected = UtcTime::new(1_659_545_693, 895_531_827).unwrap();
+        for &input in &[
+            "2022-08-03T16:54:53.895531827Z",
+            "2022-08-03t16:54:53.895531827z",
+            "2022-08-03 16:54:53.895531827Z",
+            "2022-08-03T16:54:53.895531827999Z",
+            "2022-08-03T16:54:53.895531827+00:00",
+            "2022-08-03T16:54:53.895531827-00:00",
+            "2022-08-03T18:54:53.895531827+02:00",
+            "2022-08-03T07:24:53.895531827-09:30",
+            "2022-08-04T01:54:53.895531827+09:00",
+            "+2022-08-03T16:54:53.895531827Z",
+        ] {
+            assert_eq!(UtcTime::parse_rfc3339(input), Ok(expected), "{}", input);
+        }
+
+        assert_eq!(
+            UtcTime::parse_rfc3339("2016-12-31T23:59:60Z"),
+            UtcTime::parse_rfc3339("2017-01-01T00:00:00Z"),
+        );
+        assert_eq!(
+            UtcTime::parse_rfc3339("1969-12-31T23:59:59.5Z"),
+            Ok(UtcTime::new(-1, 500_000_000).unwrap()),
+        );
+
+        for &input in &[
+            "",
+            "2022-08-03",
+            "2022-08-03T16:54:53",
+            "2022-08-03T16:54:53.Z",
+            "2022-08-03T16:54Z",
+            "2022-8-03T16:54:53Z",
+            "22-08-03T16:54:53Z",
+            "+222-08-03T16:54:53Z",
+            "2022-08-03T16:54:53ZZ",
+            "2022-08-03T16:54:53+0200",
+            "2022-08-03T16:54:53+24:00",
+            "2022-08-03T16:54:53+02:60",
+            "2022-08-03T16:54:61Z",
+            "2022-08-03T16:60:53Z",
+            "2022-08-03T24:54:53Z",
+            "2022-02-29T16:54:53Z",
+            "2022-13-03T16:54:53Z",
+            "2022-00-03T16:54:53Z",
+            "2022-08-00T16:54:53Z",
+            "2022-08-03X16:54:53Z",
+            " 2022-08-03T16:54:53Z",
+            "2022-08-03T16:54:53Z ",
+            "2022-08-03T16:54:53.８Z",
+            "+99999999999999999999-08-03T16:54:53Z",
+            "+292277026596-12-04T15:30:08Z",
+        ] {
+            assert!(UtcTime::parse_rfc3339(input).is_err(), "{}", input);
+        }
+
+        let kind = |s| UtcTime::parse_rfc3339(s).unwrap_err().kind();
+        assert_eq!(kind(""), ConversionErrorKind::Empty);
+        assert_eq!(kind("2022-08-03"), ConversionErrorKind::InvalidCharacter {
+            index: 10
+        },);
+        assert_eq!(
+            kind("2022-8-03T16:54:53Z"),
+            ConversionErrorKind::InvalidCharacter { index: 6 },
+        );
+        assert_eq!(
+            kind("2022-08-03T16:54:53+0200"),
+            ConversionErrorKind::InvalidCharacter { index: 22 },
+        );
+        assert_eq!(
+            kind("2022-08-03T16:54:53+24:00"),
+            ConversionErrorKind::OutOfRange,
+        );
+        assert_eq!(
+            kind("2022-02-29T16:54:53Z"),
+            ConversionErrorKind::OutOfRange
+        );
+        assert_eq!(
+            kind("+99999999999999999999-08-03T16:54:53Z"),
+            ConversionErrorKind::TooManyDigits,
+        );
+        assert_eq!(
+            kind("+292277026596-12-04T15:30:08Z"),
+            ConversionErrorKind::Overflow,
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut secs: i64 = 1;
+        loop {
+            for &nanos in &[0, 1, 10, 500_000_000, 999_999_999] {
+                for &secs in &[secs, -secs] {
+                    let timestamp = UtcTime::new(secs, nanos).unwrap();
+                    let string = timestamp.rfc3339().to_string();
+                    assert_eq!(UtcTime::parse_rfc3339(&string), Ok(timestamp), "{}", string);
+                }
+            }
+            secs = match secs.checked_mul(3) {
+                Some(secs) => secs,
+                None => break,
+            };
+        }
+        for &timestamp in &[UtcTime::MIN, UtcTime::MAX] {
+            let string = timestamp.rfc3339().to_string();
+            assert_eq!(UtcTime::parse_rfc3339(&string), Ok(timestamp), "{}", string);
+        }
+    }
+
+    #[test]
+    fn test_parse_const() {
+        const TIME: UtcTime = crate::utc!("2022-08-03T16:54:53.895531827Z");
+        assert_eq!(TIME, UtcTime::new(1_659_545_693, 895_531_827).unwrap());
+        const BEFORE: UtcTime = crate::utc!("1969-12-31T23:59:59.5+00:00",);
+        assert_eq!(BEFORE, UtcTime::new(-1, 500_000_000).unwrap());
+        assert_eq!(crate::utc!("-0001-01-01T00:00:00Z").to_civil().year, -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "field out of range in timestamp")]
+    fn test_parse_const_invalid() {
+        let _ = UtcTime::parse_const("2022-02-30T00:00:00Z");
+    }
+}
@@ -0,0 +1,397 @@
+use core::time::Duration;
+
+use crate::UtcTime;
+
+/// A fixed unit of time for [`UtcTime::truncate()`], [`UtcTime::round()`] and [`UtcTime::ceil()`]
+///
+/// All units are aligned to the epoch (1970-01-01 in UTC), except for [`TimeUnit::Week`],
+/// which starts on Monday as in ISO 8601.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum TimeUnit {
+    /// 1 ns
+    Nanosecond,
+    /// 1 µs
+    Microsecond,
+    /// 1 ms
+    Millisecond,
+    /// 1 s
+    Second,
+    /// 60 s
+    Minute,
+    /// 60 min
+    Hour,
+    /// 24 h, starting at midnight UTC
+    Day,
+    /// 7 days, starting on Monday at midnight UTC
+    Week,
+}
+
+impl TimeUnit {
+    /// The length of the unit
+    #[must_use]
+    pub const fn duration(self) -> Duration {
+        match self {
+            TimeUnit::Nanosecond => Duration::from_nanos(1),
+            TimeUnit::Microsecond => Duration::from_micros(1),
+            TimeUnit::Millisecond => Duration::from_millis(1),
+            TimeUnit::Second => Duration::from_secs(1),
+            TimeUnit::Minute => Duration::from_secs(60),
+            TimeUnit::Hour => Duration::from_secs(60 * 60),
+            TimeUnit::Day => Duration::from_secs(24 * 60 * 60),
+            TimeUnit::Week => Duration::from_secs(7 * 24 * 60 * 60),
+        }
+    }
+}
+
+/// How to round a [`UtcTime`] in [`UtcTime::round()`]
+///
+/// "Up" and "down" refer to the direction of time, i.e. later and earlier,
+/// even for times before 1970.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RoundingMode {
+    /// Round down to the start of the interval, the same as [`UtcTime::truncate()`]
+    Floor,
+    /// Round up to the start of the next interval, unless already at the start, the same as [`UtcTime::ceil()`]
+    Ceil,
+    /// Round to the nearest start of an interval, ties are rounded up
+    HalfUp,
+    /// Round to the nearest start of an interval, ties are rounded down
+    HalfDown,
+    /// Round to the nearest start of an interval, ties are rounded to an even multiple of the interval
+    HalfEven,
+}
+
+/// An interval to round a [`UtcTime`] to, anchored at an origin
+///
+/// You can convert a [`TimeUnit`] or a [`Duration`] into an interval.
+/// A [`Duration`] is anchored at the epoch (1970-01-01 in UTC), use [`Interval::with_origin()`]
+/// to anchor it at another time.
+///
+/// # Example
+///
+/// ```
+/// # use core::time::Duration;
+/// # use utcnow::{Interval, UtcTime};
+/// // 15 minute buckets, starting at 5 past the hour
+/// let origin = UtcTime::parse_rfc3339("2024-01-01T00:05:00Z").unwrap();
+/// let buckets = Interval::from(Duration::from_secs(15 * 60)).with_origin(origin);
+///
+/// let time = UtcTime::parse_rfc3339("2024-02-29T12:34:56Z").unwrap();
+/// assert_eq!(time.truncate(buckets).unwrap().rfc3339().to_string(), "2024-02-29T12:20:00Z");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    length: Duration,
+    origin: UtcTime,
+}
+
+impl Interval {
+    /// Monday, 1970-01-05, the start of the first week after the epoch
+    const MONDAY: UtcTime = UtcTime::from_secs(4 * 24 * 60 * 60);
+
+    /// An interval of `length`, anchored at the epoch (1970-01-01 in UTC)
+    ///
+    /// An interval of length zero leaves the value unchanged when rounding.
+    #[must_use]
+    #[inline]
+    pub const fn new(length: Duration) -> Self {
+        Self {
+            length,
+            origin: UtcTime::EPOCH,
+        }
+    }
+
+    /// Use `origin` as the start of an interval
+    ///
+    /// Any other time that is a multiple of the length apart from `origin` results in the same interval.
+    #[must_use]
+    #[inline]
+    pub const fn with_origin(self, origin: UtcTime) -> Self {
+        Self { origin, ..self }
+    }
+
+    /// The length of the interval
+    #[must_use]
+    #[inline]
+    pub const fn length(self) -> Duration {
+        self.length
+    }
+
+    /// The start of one interval
+    #[must_use]
+    #[inline]
+    pub const fn origin(self) -> UtcTime {
+        self.origin
+    }
+}
+
+impl From<TimeUnit> for Interval {
+    fn from(unit: TimeUnit) -> Self {
+        let interval = Self::new(unit.duration());
+        match unit {
+            TimeUnit::Week => interval.with_origin(Self::MONDAY),
+            _ => interval,
+        }
+    }
+}
+
+impl From<Duration> for Interval {
+    #[inline]
+    fn from(length: Duration) -> Self {
+        Self::new(length)
+    }
+}
+
+impl UtcTime {
+    /// Round down to the start of the interval, e.g. to the start of the minute
+    ///
+    /// Returns [`None`] if the result is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{TimeUnit, UtcTime};
+    /// let time = UtcTime::parse_rfc3339("1969-12-31T23:59:59.5Z").unwrap();
+    /// let truncated = time.truncate(TimeUnit::Second).unwrap();
+    /// assert_eq!(truncated.rfc3339().to_string(), "1969-12-31T23:59:59Z");
+    /// let truncated = time.truncate(TimeUnit::Day).unwrap();
+    /// assert_eq!(truncated.rfc3339().to_string(), "1969-12-31T00:00:00Z");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn truncate(self, interval: impl Into<Interval>) -> Option<Self> {
+        self.round(interval, RoundingMode::Floor)
+    }
+
+    /// Round up to the start of the next interval, unless the time is at the start of an interval
+    ///
+    /// Returns [`None`] if the result is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{TimeUnit, UtcTime};
+    /// let time = UtcTime::parse_rfc3339("1969-12-31T23:59:59.5Z").unwrap();
+    /// let ceiled = time.ceil(TimeUnit::Second).unwrap();
+    /// assert_eq!(ceiled, UtcTime::EPOCH);
+    /// assert_eq!(ceiled.ceil(TimeUnit::Hour), Some(UtcTime::EPOCH));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn ceil(self, interval: impl Into<Interval>) -> Option<Self> {
+        self.round(interval, RoundingMode::Ceil)
+    }
+
+    /// Round to the start of an interval
+    ///
+    /// Returns [`None`] if the result is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{RoundingMode, TimeUnit, UtcTime};
+    /// let time = UtcTime::parse_rfc3339("2024-02-29T12:34:56.789Z").unwrap();
+    /// let rounded = time.round(TimeUnit::Millisecond, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(rounded, time);
+    /// let rounded = time.round(TimeUnit::Second, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(rounded.rfc3339().to_string(), "2024-02-29T12:34:57Z");
+    /// let rounded = time.round(TimeUnit::Day, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(rounded.rfc3339().to_string(), "2024-03-01T00:00:00Z");
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)] // `Duration::MAX` has less than 95 bits of nanoseconds
+    pub fn round(self, interval: impl Into<Interval>, mode: RoundingMode) -> Option<Self> {
+        let interval = interval.into();
+        let length = interval.length.as_nanos() as i128;
+        if length == 0 {
+            return Some(self);
+        }
+
+        let origin = interval.origin.as_nanos();
+        let offset = self.as_nanos() - origin;
+        let remainder = offset.rem_euclid(length);
+        let floor = offset - remainder;
+        let up = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => remainder > 0,
+            RoundingMode::HalfUp => remainder * 2 >= length,
+            RoundingMode::HalfDown => remainder * 2 > length,
+            RoundingMode::HalfEven => match (remainder * 2).cmp(&length) {
+                core::cmp::Ordering::Less => false,
+                core::cmp::Ordering::Equal => (floor / length) % 2 != 0,
+                core::cmp::Ordering::Greater => true,
+            },
+        };
+        let result = if up { floor + length } else { floor };
+        Self::from_nanos(origin + result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use crate::{Interval, RoundingMode, TimeUnit, UtcTime};
+
+    fn utc(s: &str) -> UtcTime {
+        UtcTime::parse_rfc3339(s).unwrap()
+    }
+
+    const ALL_MODES: [RoundingMode; 5] = [
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::HalfUp,
+        RoundingMode::HalfDown,
+        RoundingMode::HalfEven,
+    ];
+
+    #[test]
+    fn test_units() {
+        let time = utc("2024-02-29T12:34:56.789123456Z");
+        for &(unit, floor, ceil) in &[
+            (
+                TimeUnit::Nanosecond,
+                "2024-02-29T12:34:56.789123456Z",
+                "2024-02-29T12:34:56.789123456Z",
+            ),
+            (
+                TimeUnit::Microsecond,
+                "2024-02-29T12:34:56.789123Z",
+                "2024-02-29T12:34:56.789124Z",
+            ),
+            (
+                TimeUnit::Millisecond,
+                "2024-02-29T12:34:56.789Z",
+                "2024-02-29T12:34:56.790Z",
+            ),
+            (
+                TimeUnit::Second,
+                "2024-02-29T12:34:56Z",
+                "2024-02-29T12:34:57Z",
+            ),
+            (
+                TimeUnit::Minute,
+                "2024-02-29T12:34:00Z",
+                "2024-02-29T12:35:00Z",
+            ),
+            (
+                TimeUnit::Hour,
+                "2024-02-29T12:00:00Z",
+                "2024-02-29T13:00:00Z",
+            ),
+            (
+                TimeUnit::Day,
+                "2024-02-29T00:00:00Z",
+                "2024-03-01T00:00:00Z",
+            ),
+            (
+                TimeUnit::Week,
+                "2024-02-26T00:00:00Z",
+                "2024-03-04T00:00:00Z",
+            ),
+        ] {
+            assert_eq!(time.truncate(unit), Some(utc(floor)), "{:?}", unit);
+            assert_eq!(time.ceil(unit), Some(utc(ceil)), "{:?}", unit);
+            for &mode in &ALL_MODES {
+                let floor = utc(floor);
+                assert_eq!(
+                    floor.round(unit, mode),
+                    Some(floor),
+                    "{:?} {:?}",
+                    unit,
+                    mode
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_negative() {
+        let time = utc("1969-12-31T23:59:59.5Z");
+        let expected = [-1, 0, 0, -1, 0];
+        for (&mode, &secs) in ALL_MODES.iter().zip(&expected) {
+            assert_eq!(
+                time.round(TimeUnit::Second, mode),
+                Some(UtcTime::from_secs(secs)),
+                "{:?}",
+                mode,
+            );
+        }
+
+        let time = utc("1969-12-31T23:59:58.5Z");
+        let expected = [-2, -1, -1, -2, -2];
+        for (&mode, &secs) in ALL_MODES.iter().zip(&expected) {
+            assert_eq!(
+                time.round(TimeUnit::Second, mode),
+                Some(UtcTime::from_secs(secs)),
+                "{:?}",
+                mode,
+            );
+        }
+
+        let time = utc("1969-12-31T23:59:59.999999999Z");
+        assert_eq!(
+            time.truncate(TimeUnit::Day),
+            Some(utc("1969-12-31T00:00:00Z"))
+        );
+        assert_eq!(time.ceil(TimeUnit::Day), Some(UtcTime::EPOCH));
+        assert_eq!(
+            time.truncate(TimeUnit::Week),
+            Some(utc("1969-12-29T00:00:00Z"))
+        );
+        assert_eq!(
+            time.truncate(TimeUnit::Millisecond),
+            Some(utc("1969-12-31T23:59:59.999Z")),
+        );
+    }
+
+    #[test]
+    fn test_interval() {
+        let time = utc("2024-02-29T12:34:56Z");
+        let interval = Interval::from(Duration::from_secs(15 * 60));
+        assert_eq!(time.truncate(interval), Some(utc("2024-02-29T12:30:00Z")));
+        let interval = interval.with_origin(utc("1900-01-01T00:05:00Z"));
+        assert_eq!(time.truncate(interval), Some(utc("2024-02-29T12:20:00Z")));
+        assert_eq!(time.ceil(interval), Some(utc("2024-02-29T12:35:00Z")));
+        assert_eq!(
+            time.round(interval, RoundingMode::HalfUp),
+            Some(utc("2024-02-29T12:35:00Z")),
+        );
+
+        let interval = Interval::new(Duration::from_millis(1500));
+        assert_eq!(interval.origin(), UtcTime::EPOCH);
+        assert_eq!(interval.length(), Duration::from_millis(1500));
+        let time = UtcTime::from_millis(-750).unwrap();
+        for (&mode, &millis) in ALL_MODES.iter().zip(&[-1500, 0, 0, -1500, 0]) {
+            let expected = UtcTime::from_millis(millis);
+            assert_eq!(time.round(interval, mode), expected, "{:?}", mode);
+        }
+        let time = UtcTime::from_millis(2250).unwrap();
+        let expected = UtcTime::from_millis(3000);
+        assert_eq!(time.round(interval, RoundingMode::HalfEven), expected);
+
+        let zero = Interval::new(Duration::ZERO);
+        for &mode in &ALL_MODES {
+            assert_eq!(time.round(zero, mode), Some(time));
+        }
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_eq!(
+            UtcTime::MAX.truncate(TimeUnit::Second),
+            Some(UtcTime::from_secs(i64::MAX))
+        );
+        assert_eq!(UtcTime::MAX.ceil(TimeUnit::Second), None);
+        assert_eq!(UtcTime::MIN.ceil(TimeUnit::Second), Some(UtcTime::MIN));
+        assert_eq!(UtcTime::MIN.truncate(TimeUnit::Minute), None);
+        assert!(UtcTime::MIN.ceil(TimeUnit::Week).is_some());
+        assert_eq!(UtcTime::MIN.truncate(Duration::MAX), None);
+        assert_eq!(
+            UtcTime::MAX.round(Duration::MAX, RoundingMode::HalfEven),
+            Some(UtcTime::EPOCH)
+        );
+    }
+}
@@ -0,0 +1,841 @@
+//! Alternative representations of [`UtcTime`] for [`serde`](https://docs.rs/serde/1/serde/)
+//!
+//! This module is only available with the feature `"serde"`.
+//!
+//! By default [`UtcTime`] is serialized as a tuple of the seconds and the nanoseconds since epoch.
+//! Use the modules with [`#[serde(with = "…")]`](https://serde.rs/field-attrs.html#with) to choose
+//! another representation for a field. Every module has a submodule `option` for [`Option<UtcTime>`] fields.
+//!
+//! | Module               | Example `2022-08-03T16:54:53.895Z` | Precision                   |
+//! |----------------------|------------------------------------|-----------------------------|
+//! | [`rfc3339`]          | `"2022-08-03T16:54:53.895Z"`       | lossless                    |
+//! | [`decimal_string`]   | `"1659545693.895000000"`           | lossless                    |
+//! | [`secs`]             | `1659545693`                       | rounded down to seconds     |
+//! | [`millis`]           | `1659545693895`                    | rounded down to millis      |
+//! | [`micros`]           | `1659545693895000`                 | rounded down to micros      |
+//! | [`nanos`]            | `1659545693895000000`              | lossless                    |
+//! | [`float_secs`]       | `1659545693.895`                   | see [`UtcTime::as_secs_f64()`] |
+//!
+//! With the feature `"prost"`, the module `proto3_json` writes the canonical JSON representation of a
+//! `google.protobuf.Timestamp`, e.g. `"2022-08-03T16:54:53.895Z"`.
+//!
+//! Integers are serialized and deserialized as `i64`, because not every data format supports `i128`.
+//! Serialization fails if the value does not fit, e.g. [`nanos`] covers about ±292 years around 1970.
+//!
+//! The module [`flexible`] accepts all of these representations, and a few more, for ingesting
+//! data written by third parties.
+//!
+//! With the feature `"serde-human-readable"`, the default implementation writes the format of [`rfc3339`]
+//! if the data format is [human readable](::serde::Serializer::is_human_readable), e.g. JSON.
+//! Human readable input is then read with [`flexible`], so data written without the feature stays readable.
+//!
+//! # Example
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use utcnow::UtcTime;
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "utcnow::serde::rfc3339")]
+//!     created: UtcTime,
+//!     #[serde(with = "utcnow::serde::millis::option")]
+//!     deleted: Option<UtcTime>,
+//! }
+//!
+//! let event = Event {
+//!     created: UtcTime::new(1_659_545_693, 895_000_000).unwrap(),
+//!     deleted: None,
+//! };
+//! let json = serde_json::to_string(&event).unwrap();
+//! assert_eq!(json, r#"{"created":"2022-08-03T16:54:53.895Z","deleted":null}"#);
+//! assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+//! ```
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use ::serde::Serializer;
+use ::serde::de::{Error, Visitor};
+
+use crate::UtcTime;
+
+/// Implement a submodule `option` for `Option<UtcTime>`, using `serialize` and `deserialize` of the parent module
+macro_rules! option_module {
+    ($name:ident) => {
+        #[doc = concat!("Like [`", stringify!($name), "`](super), but for [`Option<UtcTime>`](crate::UtcTime)")]
+        pub mod option {
+            use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            use crate::UtcTime;
+
+            struct Wrapper(UtcTime);
+
+            impl Serialize for Wrapper {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::serialize(&self.0, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for Wrapper {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    super::deserialize(deserializer).map(Wrapper)
+                }
+            }
+
+            /// Serialize an [`Option<UtcTime>`](crate::UtcTime)
+            ///
+            /// # Errors
+            ///
+            /// Fails if the serializer fails.
+            #[allow(clippy::ref_option)] // the signature is dictated by `#[serde(with = "…")]`
+            pub fn serialize<S: Serializer>(
+                value: &Option<UtcTime>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                match *value {
+                    Some(value) => serializer.serialize_some(&Wrapper(value)),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            /// Deserialize an [`Option<UtcTime>`](crate::UtcTime)
+            ///
+            /// # Errors
+            ///
+            /// Fails if the input is malformed, or if the value is out of range.
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<UtcTime>, D::Error> {
+                Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(value)| value))
+            }
+        }
+    };
+}
+
+/// Serialize an integer as `i64`, or fail if it does not fit
+///
+/// Deserialization has to request an `i64`, too, or data formats that are not self-describing break.
+fn serialize_int<S: Serializer>(value: i128, serializer: S) -> Result<S::Ok, S::Error> {
+    match i64::try_from(value) {
+        Ok(value) => serializer.serialize_i64(value),
+        Err(_) => Err(::serde::ser::Error::custom(
+            "UtcTime out of range for an i64",
+        )),
+    }
+}
+
+/// Accepts any integer, and converts it with `from_int`
+struct IntVisitor {
+    expecting: &'static str,
+    from_int: fn(i128) -> Option<UtcTime>,
+}
+
+impl IntVisitor {
+    fn convert<E: Error>(&self, value: i128) -> Result<UtcTime, E> {
+        (self.from_int)(value).ok_or_else(|| E::custom("UtcTime out of range"))
+    }
+}
+
+impl Visitor<'_> for IntVisitor {
+    type Value = UtcTime;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.convert(v.into())
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.convert(v.into())
+    }
+
+    fn visit_i128<E: Error>(self, v: i128) -> Result<Self::Value, E> {
+        self.convert(v)
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+        let v = i128::try_from(v).map_err(|_| E::custom("UtcTime out of range"))?;
+        self.convert(v)
+    }
+}
+
+/// Accepts a string, and converts it with `from_str`
+struct StrVisitor {
+    expecting: &'static str,
+    from_str: fn(&str) -> Result<UtcTime, crate::ConversionError>,
+}
+
+impl Visitor<'_> for StrVisitor {
+    type Value = UtcTime;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        (self.from_str)(v).map_err(E::custom)
+    }
+}
+
+macro_rules! int_module {
+    ($name:ident, $unit:literal, $as_int:expr, $from_int:expr) => {
+        #[doc = concat!("Represent a [`UtcTime`] as an integer number of ", $unit, " since epoch (1970-01-01 in UTC)")]
+        ///
+        /// Serialization rounds down, so times before 1970 are rounded away from zero.
+        pub mod $name {
+            use ::serde::{Deserializer, Serializer};
+
+            use crate::UtcTime;
+
+            #[doc = concat!("Serialize a [`UtcTime`] as ", $unit, " since epoch")]
+            ///
+            /// # Errors
+            ///
+            /// Fails if the value does not fit into an `i64`, or if the serializer fails.
+            #[allow(clippy::trivially_copy_pass_by_ref)] // the signature is dictated by `#[serde(with = "…")]`
+            pub fn serialize<S: Serializer>(value: &UtcTime, serializer: S) -> Result<S::Ok, S::Error> {
+                let as_int: fn(UtcTime) -> i128 = $as_int;
+                super::serialize_int(as_int(*value), serializer)
+            }
+
+            #[doc = concat!("Deserialize a [`UtcTime`] from ", $unit, " since epoch")]
+            ///
+            /// # Errors
+            ///
+            /// Fails if the input is not an integer, or if the value is out of range.
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTime, D::Error> {
+                deserializer.deserialize_i64(super::IntVisitor {
+                    expecting: concat!("an integer number of ", $unit, " since epoch"),
+                    from_int: $from_int,
+                })
+            }
+
+            option_module!($name);
+        }
+    };
+}
+
+fn secs_as_int(time: UtcTime) -> i128 {
+    time.as_secs().into()
+}
+
+fn secs_from_int(secs: i128) -> Option<UtcTime> {
+    i64::try_from(secs).ok().map(UtcTime::from_secs)
+}
+
+int_module!(secs, "seconds", super::secs_as_int, super::secs_from_int);
+int_module!(
+    millis,
+    "milliseconds",
+    UtcTime::as_millis,
+    UtcTime::from_millis
+);
+int_module!(
+    micros,
+    "microseconds",
+    UtcTime::as_micros,
+    UtcTime::from_micros
+);
+int_module!(nanos, "nanoseconds", UtcTime::as_nanos, UtcTime::from_nanos);
+
+/// Represent a [`UtcTime`] as an [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) string
+///
+/// The string is written by [`UtcTime::rfc3339()`], and read by [`UtcTime::parse_rfc3339()`].
+pub mod rfc3339 {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::UtcTime;
+
+    /// Serialize a [`UtcTime`] as an RFC 3339 string, e.g. `"2022-08-03T16:54:53.895Z"`
+    ///
+    /// # Errors
+    ///
+    /// Fails if the serializer fails.
+    #[allow(clippy::trivially_copy_pass_by_ref)] // the signature is dictated by `#[serde(with = "…")]`
+    pub fn serialize<S: Serializer>(value: &UtcTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&value.rfc3339())
+    }
+
+    /// Deserialize a [`UtcTime`] from an RFC 3339 string
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is not a string, if the string is malformed, or if the value is out of range.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTime, D::Error> {
+        deserializer.deserialize_str(super::StrVisitor {
+            expecting: "an RFC 3339 timestamp",
+            from_str: UtcTime::parse_rfc3339,
+        })
+    }
+
+    option_module!(rfc3339);
+}
+
+/// Represent a [`UtcTime`] as a string with a signed decimal number of seconds since epoch
+///
+/// The string is written by [`UtcTime::to_decimal()`], and read by [`UtcTime::from_decimal()`],
+/// e.g. `"-1.500000000"` is one and a half seconds before 1970.
+pub mod decimal_string {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::UtcTime;
+
+    /// Serialize a [`UtcTime`] as a decimal string, e.g. `"1659545693.895000000"`
+    ///
+    /// # Errors
+    ///
+    /// Fails if the serializer fails.
+    #[allow(clippy::trivially_copy_pass_by_ref)] // the signature is dictated by `#[serde(with = "…")]`
+    pub fn serialize<S: Serializer>(value: &UtcTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&value.to_decimal())
+    }
+
+    /// Deserialize a [`UtcTime`] from a decimal string
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is not a string, if the string is malformed, or if the value is out of range.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTime, D::Error> {
+        deserializer.deserialize_str(super::StrVisitor {
+            expecting: "a decimal number of seconds since epoch",
+            from_str: UtcTime::from_decimal,
+        })
+    }
+
+    option_module!(decimal_string);
+}
+
+/// Represent a [`UtcTime`] in the [proto3 JSON mapping](https://protobuf.dev/programming-guides/json/)
+/// of `google.protobuf.Timestamp`
+///
+/// The string is written by [`UtcTime::to_proto3_json()`], and read by [`UtcTime::parse_proto3_json()`].
+/// This module is only available with the features `"serde"` and `"prost"`.
+#[cfg(feature = "prost")]
+pub mod proto3_json {
+    use ::serde::ser::Error;
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::UtcTime;
+
+    /// Serialize a [`UtcTime`] as an RFC 3339 string with 0, 3, 6 or 9 fractional digits
+    ///
+    /// # Errors
+    ///
+    /// Fails if the year is not in the range 0001 to 9999, or if the serializer fails.
+    #[allow(clippy::trivially_copy_pass_by_ref)] // the signature is dictated by `#[serde(with = "…")]`
+    pub fn serialize<S: Serializer>(value: &UtcTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&value.to_proto3_json().map_err(S::Error::custom)?)
+    }
+
+    /// Deserialize a [`UtcTime`] from an RFC 3339 string
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is not a string, if the string is malformed, or if the value is out of range.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTime, D::Error> {
+        deserializer.deserialize_str(super::StrVisitor {
+            expecting: "a proto3 JSON timestamp",
+            from_str: UtcTime::parse_proto3_json,
+        })
+    }
+
+    option_module!(proto3_json);
+}
+
+/// Represent a [`UtcTime`] as a floating point number of seconds since epoch (1970-01-01 in UTC)
+///
+/// See [`UtcTime::as_secs_f64()`] and [`UtcTime::from_secs_f64()`] for the precision and the rounding.
+/// Integers are accepted, too.
+pub mod float_secs {
+    use core::convert::TryFrom;
+    use core::fmt;
+
+    use ::serde::de::{Error, Visitor};
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::UtcTime;
+
+    /// Serialize a [`UtcTime`] as an `f64`, e.g. `1659545693.895`
+    ///
+    /// # Errors
+    ///
+    /// Fails if the serializer fails.
+    #[allow(clippy::trivially_copy_pass_by_ref)] // the signature is dictated by `#[serde(with = "…")]`
+    pub fn serialize<S: Serializer>(value: &UtcTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(value.as_secs_f64())
+    }
+
+    /// Deserialize a [`UtcTime`] from a number
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is not a number, or if the value is out of range.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTime, D::Error> {
+        deserializer.deserialize_f64(FloatVisitor)
+    }
+
+    struct FloatVisitor;
+
+    impl Visitor<'_> for FloatVisitor {
+        type Value = UtcTime;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a number of seconds since epoch")
+        }
+
+        fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+            UtcTime::from_secs_f64(v).ok_or_else(|| E::custom("UtcTime out of range"))
+        }
+
+        fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+            Ok(UtcTime::from_secs(v))
+        }
+
+        fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+            let v = i64::try_from(v).map_err(|_| E::custom("UtcTime out of range"))?;
+            Ok(UtcTime::from_secs(v))
+        }
+    }
+
+    option_module!(float_secs);
+}
+
+/// Accept any of the common representations of a [`UtcTime`] in self-describing data formats
+///
+/// This module is meant for ingesting data written by third parties, e.g. JSON, YAML or TOML.
+/// [`deserialize()`](flexible::deserialize) accepts:
+///
+/// * the default representation, a tuple `[secs, nanos]`,
+/// * a map `{"secs": secs, "nanos": nanos}`,
+/// * an integer number of seconds since epoch, e.g. `1659545693`,
+/// * a floating point number of seconds since epoch, e.g. `1659545693.895`,
+/// * a string with a decimal number of seconds since epoch, e.g. `"1659545693.895"`, or
+/// * an [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) string, e.g. `"2022-08-03T16:54:53.895Z"`.
+///
+/// Strings that contain a `':'` are parsed as RFC 3339, other strings as decimal numbers.
+/// In the tuple and in the map, excess `nanos` are carried into `secs`, like in [`UtcTime::new()`].
+/// The error message tells which of the representations was malformed.
+///
+/// The data format must support [`Deserializer::deserialize_any()`](::serde::Deserializer::deserialize_any), so e.g. `bincode` won't work.
+/// [`serialize()`](flexible::serialize) writes the default representation.
+pub mod flexible {
+    use core::convert::TryFrom;
+    use core::fmt;
+
+    use ::serde::de::{Error, IgnoredAny, MapAccess, SeqAccess, Unexpected, Visitor};
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::UtcTime;
+
+    const INT_OUT_OF_RANGE: &str = "invalid integer seconds: UtcTime out of range";
+
+    /// Serialize a [`UtcTime`] with its default representation
+    ///
+    /// # Errors
+    ///
+    /// Fails if the serializer fails.
+    #[allow(clippy::trivially_copy_pass_by_ref)] // the signature is dictated by `#[serde(with = "…")]`
+    pub fn serialize<S: Serializer>(value: &UtcTime, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    /// Deserialize a [`UtcTime`] from any of the supported representations
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input matches none of the representations, if it is malformed,
+    /// or if the value is out of range.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UtcTime, D::Error> {
+        deserializer.deserialize_any(FlexibleVisitor)
+    }
+
+    fn from_parts<E: Error>(form: &str, secs: i64, nanos: u32) -> Result<UtcTime, E> {
+        UtcTime::new(secs, nanos)
+            .ok_or_else(|| E::custom(format_args!("invalid {}: UtcTime out of range", form)))
+    }
+
+    fn from_int<E: Error>(secs: i128) -> Result<UtcTime, E> {
+        match i64::try_from(secs) {
+            Ok(secs) => Ok(UtcTime::from_secs(secs)),
+            Err(_) => Err(E::custom(INT_OUT_OF_RANGE)),
+        }
+    }
+
+    struct FlexibleVisitor;
+
+    impl<'de> Visitor<'de> for FlexibleVisitor {
+        type Value = UtcTime;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(
+                "a timestamp as [secs, nanos] tuple, {secs, nanos} map, number of seconds, \
+                 decimal string or RFC 3339 string",
+            )
+        }
+
+        fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+            Ok(UtcTime::from_secs(v))
+        }
+
+        fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+            from_int(v.into())
+        }
+
+        fn visit_i128<E: Error>(self, v: i128) -> Result<Self::Value, E> {
+            from_int(v)
+        }
+
+        fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+            match i128::try_from(v) {
+                Ok(v) => from_int(v),
+                Err(_) => Err(E::custom(INT_OUT_OF_RANGE)),
+            }
+        }
+
+        fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+            UtcTime::from_secs_f64(v).ok_or_else(|| {
+                E::custom(format_args!(
+                    "invalid float seconds: UtcTime out of range, got {}",
+                    v,
+                ))
+            })
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            if v.contains(':') {
+                UtcTime::parse_rfc3339(v)
+                    .map_err(|err| E::custom(format_args!("invalid RFC 3339 timestamp: {}", err)))
+            } else {
+                UtcTime::from_decimal(v)
+                    .map_err(|err| E::custom(format_args!("invalid decimal string: {}", err)))
+            }
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            const FORM: &str = "[secs, nanos] tuple";
+
+            let wrap = |err: A::Error| A::Error::custom(format_args!("invalid {}: {}", FORM, err));
+            let secs = seq.next_element::<i64>().map_err(wrap)?;
+            let secs = secs.ok_or_else(|| A::Error::invalid_length(0, &FORM))?;
+            let nanos = seq.next_element::<u32>().map_err(wrap)?;
+            let nanos = nanos.ok_or_else(|| A::Error::invalid_length(1, &FORM))?;
+            if seq.next_element::<IgnoredAny>()?.is_some() {
+                return Err(A::Error::invalid_length(3, &FORM));
+            }
+            from_parts(FORM, secs, nanos)
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            const FORM: &str = "{secs, nanos} map";
+
+            let wrap = |err: A::Error| A::Error::custom(format_args!("invalid {}: {}", FORM, err));
+            let mut secs = None;
+            let mut nanos = None;
+            while let Some(field) = map.next_key::<Field>().map_err(wrap)? {
+                match field {
+                    Field::Secs if secs.is_some() => return Err(A::Error::duplicate_field("secs")),
+                    Field::Nanos if nanos.is_some() => {
+                        return Err(A::Error::duplicate_field("nanos"));
+                    },
+                    Field::Secs => secs = Some(map.next_value::<i64>().map_err(wrap)?),
+                    Field::Nanos => nanos = Some(map.next_value::<u32>().map_err(wrap)?),
+                }
+            }
+            let secs = secs.ok_or_else(|| A::Error::missing_field("secs"))?;
+            let nanos = nanos.ok_or_else(|| A::Error::missing_field("nanos"))?;
+            from_parts(FORM, secs, nanos)
+        }
+    }
+
+    enum Field {
+        Secs,
+        Nanos,
+    }
+
+    impl<'de> Deserialize<'de> for Field {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_identifier(FieldVisitor)
+        }
+    }
+
+    struct FieldVisitor;
+
+    impl Visitor<'_> for FieldVisitor {
+        type Value = Field;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("`secs` or `nanos`")
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            match v {
+                "secs" => Ok(Field::Secs),
+                "nanos" => Ok(Field::Nanos),
+                _ => Err(E::unknown_field(v, &["secs", "nanos"])),
+            }
+        }
+
+        fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            match v {
+                b"secs" => Ok(Field::Secs),
+                b"nanos" => Ok(Field::Nanos),
+                _ => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+            }
+        }
+    }
+
+    option_module!(flexible);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UtcTime;
+
+    macro_rules! to_json {
+        ($module:path, $ty:ty, $value:expr) => {{
+            struct Wrapper($ty);
+
+            impl ::serde::Serialize for Wrapper {
+                fn serialize<S: ::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                    use $module as module;
+                    module::serialize(&self.0, s)
+                }
+            }
+
+            serde_json::to_string(&Wrapper($value)).unwrap()
+        }};
+    }
+
+    macro_rules! round_trip {
+        ($module:ident, $value:expr, $json:expr) => {{
+            let value: UtcTime = $value;
+            let json = to_json!(super::$module, UtcTime, value);
+            assert_eq!(json, $json);
+            let mut de = serde_json::Deserializer::from_str(&json);
+            super::$module::deserialize(&mut de).unwrap()
+        }};
+    }
+
+    #[test]
+    fn test_modules() {
+        let time = UtcTime::new(1_659_545_693, 895_531_827).unwrap();
+        let back = round_trip!(rfc3339, time, r#""2022-08-03T16:54:53.895531827Z""#);
+        assert_eq!(back, time);
+        let back = round_trip!(decimal_string, time, r#""1659545693.895531827""#);
+        assert_eq!(back, time);
+        let back = round_trip!(secs, time, "1659545693");
+        assert_eq!(back, UtcTime::from_secs(1_659_545_693));
+        let back = round_trip!(millis, time, "1659545693895");
+        assert_eq!(back, UtcTime::new(1_659_545_693, 895_000_000).unwrap());
+        let back = round_trip!(micros, time, "1659545693895531");
+        assert_eq!(back, UtcTime::new(1_659_545_693, 895_531_000).unwrap());
+        let back = round_trip!(nanos, time, "1659545693895531827");
+        assert_eq!(back, time);
+        let back = round_trip!(float_secs, time, "1659545693.895532");
+        assert_eq!(back.as_secs(), time.as_secs());
+        assert!((back.as_nanos() - time.as_nanos()).abs() < 1_000);
+    }
+
+    #[test]
+    fn test_negative() {
+        let time = UtcTime::new(-2, 500_000_000).unwrap();
+        assert_eq!(
+            round_trip!(rfc3339, time, r#""1969-12-31T23:59:58.5Z""#),
+            time
+        );
+        assert_eq!(round_trip!(decimal_string, time, r#""-1.500000000""#), time);
+        assert_eq!(round_trip!(secs, time, "-2"), UtcTime::from_secs(-2));
+        assert_eq!(round_trip!(millis, time, "-1500"), time);
+        assert_eq!(round_trip!(micros, time, "-1500000"), time);
+        assert_eq!(round_trip!(nanos, time, "-1500000000"), time);
+        assert_eq!(round_trip!(float_secs, time, "-1.5"), time);
+    }
+
+    #[test]
+    fn test_large() {
+        let ser = serde_json::value::Serializer;
+        assert!(super::nanos::serialize(&UtcTime::MAX, ser).is_err());
+        let ser = serde_json::value::Serializer;
+        assert!(super::millis::serialize(&UtcTime::MAX, ser).is_err());
+        let max = UtcTime::from_nanos(i64::MAX.into()).unwrap();
+        assert_eq!(round_trip!(nanos, max, "9223372036854775807"), max);
+
+        let mut de = serde_json::Deserializer::from_str("9223372036854775808000000000");
+        assert!(super::nanos::deserialize(&mut de).is_err());
+        let mut de = serde_json::Deserializer::from_str("9223372036854775808");
+        assert!(super::secs::deserialize(&mut de).is_err());
+        let mut de = serde_json::Deserializer::from_str("1e300");
+        assert!(super::float_secs::deserialize(&mut de).is_err());
+    }
+
+    /// `bincode` is not self-describing, so the integers must be read with the width they were written with
+    #[test]
+    fn test_fixed_width() {
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Times {
+            #[serde(with = "super::secs")]
+            secs: UtcTime,
+            #[serde(with = "super::millis")]
+            millis: UtcTime,
+            #[serde(with = "super::micros::option")]
+            micros: Option<UtcTime>,
+            #[serde(with = "super::nanos")]
+            nanos: UtcTime,
+        }
+
+        let times = Times {
+            secs: UtcTime::from_secs(-1_659_545_693),
+            millis: UtcTime::from_millis(1_659_545_693_895).unwrap(),
+            micros: Some(UtcTime::from_micros(1_659_545_693_895_531).unwrap()),
+            nanos: UtcTime::from_nanos(1_659_545_693_895_531_827).unwrap(),
+        };
+        let bytes = bincode::serialize(&times).unwrap();
+        assert_eq!(bytes.len(), 8 + 8 + 1 + 8 + 8);
+        assert_eq!(&bytes[..8], &(-1_659_545_693_i64).to_le_bytes());
+        assert_eq!(bincode::deserialize::<Times>(&bytes).unwrap(), times);
+    }
+
+    #[cfg(feature = "prost")]
+    #[test]
+    fn test_proto3_json() {
+        let time = UtcTime::new(1_659_545_693, 895_500_000).unwrap();
+        let back = round_trip!(proto3_json, time, r#""2022-08-03T16:54:53.895500Z""#);
+        assert_eq!(back, time);
+
+        let ser = serde_json::value::Serializer;
+        assert!(super::proto3_json::serialize(&UtcTime::MIN, ser).is_err());
+        let mut de = serde_json::Deserializer::from_str(r#""+10000-01-01T00:00:00Z""#);
+        assert!(super::proto3_json::deserialize(&mut de).is_err());
+    }
+
+    #[test]
+    fn test_option() {
+        let time = Some(UtcTime::new(1, 5).unwrap());
+        let json = to_json!(super::nanos::option, Option<UtcTime>, time);
+        assert_eq!(json, "1000000005");
+        let mut de = serde_json::Deserializer::from_str(&json);
+        assert_eq!(super::nanos::option::deserialize(&mut de).unwrap(), time);
+
+        let json = to_json!(super::rfc3339::option, Option<UtcTime>, None);
+        assert_eq!(json, "null");
+        let mut de = serde_json::Deserializer::from_str(&json);
+        assert_eq!(super::rfc3339::option::deserialize(&mut de).unwrap(), None);
+    }
+
+    fn flexible(json: &str) -> Result<UtcTime, serde_json::Error> {
+        let mut de = serde_json::Deserializer::from_str(json);
+        let value = super::flexible::deserialize(&mut de)?;
+        de.end()?;
+        Ok(value)
+    }
+
+    #[test]
+    fn test_flexible() {
+        let time = UtcTime::new(1_659_545_693, 895_000_000).unwrap();
+        assert_eq!(flexible("[1659545693,895000000]").unwrap(), time);
+        assert_eq!(
+            flexible(r#"{"secs":1659545693,"nanos":895000000}"#).unwrap(),
+            time
+        );
+        assert_eq!(
+            flexible(r#"{"nanos":895000000,"secs":1659545693}"#).unwrap(),
+            time
+        );
+        assert_eq!(
+            flexible("-1.5").unwrap(),
+            UtcTime::new(-2, 500_000_000).unwrap()
+        );
+        assert_eq!(flexible(r#""1659545693.895""#).unwrap(), time);
+        assert_eq!(flexible(r#""2022-08-03T16:54:53.895Z""#).unwrap(), time);
+        assert_eq!(
+            flexible("1659545693").unwrap(),
+            UtcTime::from_secs(1_659_545_693)
+        );
+        assert_eq!(flexible("-1").unwrap(), UtcTime::from_secs(-1));
+        assert_eq!(
+            flexible(r#""-1.5""#).unwrap(),
+            UtcTime::new(-2, 500_000_000).unwrap()
+        );
+
+        // nanos are carried into secs, like in `UtcTime::new()`
+        assert_eq!(
+            flexible("[1659545693,1000000000]").unwrap(),
+            UtcTime::from_secs(1_659_545_694)
+        );
+        assert_eq!(
+            flexible(r#"{"secs":1659545693,"nanos":1895000000}"#).unwrap(),
+            UtcTime::new(1_659_545_694, 895_000_000).unwrap()
+        );
+
+        // serialization uses the default representation
+        let expected = serde_json::to_string(&time).unwrap();
+        let json = to_json!(super::flexible, UtcTime, time);
+        assert_eq!(json, expected);
+        let json = to_json!(super::flexible::option, Option<UtcTime>, Some(time));
+        assert_eq!(json, expected);
+        assert_eq!(flexible(&json).unwrap(), time);
+    }
+
+    #[test]
+    fn test_flexible_errors() {
+        fn err(json: &str) -> String {
+            flexible(json).unwrap_err().to_string()
+        }
+
+        // the carried nanos overflow `secs`
+        let msg = err("[9223372036854775807,1000000000]");
+        assert_eq!(
+            msg,
+            "invalid [secs, nanos] tuple: UtcTime out of range at line 1 column 32",
+        );
+        let msg = err(r#"{"secs":9223372036854775807,"nanos":1000000000}"#);
+        assert!(
+            msg.starts_with("invalid {secs, nanos} map: UtcTime out of range"),
+            "{}",
+            msg
+        );
+
+        let msg = err(r#"[1659545693,"0"]"#);
+        assert!(msg.starts_with("invalid [secs, nanos] tuple"), "{}", msg);
+        let msg = err("[1659545693]");
+        assert!(msg.contains("[secs, nanos] tuple"), "{}", msg);
+        assert!(err("[1,2,3]").contains("[secs, nanos] tuple"));
+        let msg = err(r#"{"secs":1659545693,"nanos":-1}"#);
+        assert!(msg.starts_with("invalid {secs, nanos} map"), "{}", msg);
+        assert!(err(r#"{"secs":1659545693}"#).contains("missing field `nanos`"));
+        assert!(err(r#"{"secs":1,"nanos":0,"secs":1}"#).contains("duplicate field `secs`"));
+        assert!(err(r#"{"secs":1,"nanos":0,"millis":1}"#).contains("unknown field `millis`"));
+
+        let msg = err(r#""2022-08-03T25:54:53Z""#);
+        assert!(msg.starts_with("invalid RFC 3339 timestamp"), "{}", msg);
+        let msg = err(r#""2022-08-03""#);
+        assert!(msg.starts_with("invalid decimal string"), "{}", msg);
+        let msg = err("1e300");
+        assert!(msg.starts_with("invalid float seconds"), "{}", msg);
+        let msg = err("18446744073709551615");
+        assert!(msg.starts_with("invalid integer seconds"), "{}", msg);
+        let msg = err("true");
+        assert!(msg.contains("RFC 3339 string"), "{}", msg);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut de = serde_json::Deserializer::from_str(r#""2022-02-30T00:00:00Z""#);
+        let err = super::rfc3339::deserialize(&mut de).unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{}", err);
+
+        let mut de = serde_json::Deserializer::from_str(r#""1500""#);
+        assert!(super::millis::deserialize(&mut de).is_err());
+
+        let mut de = serde_json::Deserializer::from_str(r#""1.5""#);
+        let err = super::float_secs::deserialize(&mut de).unwrap_err();
+        assert!(err.to_string().contains("seconds since epoch"), "{}", err);
+
+        let mut de = serde_json::Deserializer::from_str("12");
+        let err = super::decimal_string::deserialize(&mut de).unwrap_err();
+        assert!(err.to_string().contains("decimal"), "{}", err);
+    }
+}
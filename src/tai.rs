@@ -0,0 +1,656 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+#[cfg(feature = "std")]
+use std::sync::{Arc, PoisonError, RwLock};
+
+#[cfg(feature = "std")]
+use crate::lazy::LazyBox;
+use crate::u30::U30;
+use crate::{RawClockReading, UtcTime};
+
+/// A timestamp in International Atomic Time (TAI)
+///
+/// The value counts SI seconds since 1970-01-01T00:00:00 TAI, i.e. the same scale as `CLOCK_TAI` on Linux.
+/// Unlike [`UtcTime`] the scale does not stop or repeat during a leap second,
+/// so the difference of two timestamps is the real elapsed time.
+///
+/// Use [`UtcTime::to_tai()`] and [`TaiTime::to_utc()`] to convert between the scales.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaiTime {
+    secs: i64,
+    nanos: U30,
+}
+
+impl TaiTime {
+    /// 1970-01-01T00:00:00 TAI, i.e. 1969-12-31T23:59:52 in UTC
+    pub const EPOCH: TaiTime = TaiTime {
+        secs: 0,
+        nanos: U30::ZERO,
+    };
+
+    /// Create a new timestamp
+    ///
+    /// Returns `None` if `nanos` is not in the range `0..1_000_000_000`.
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn new(secs: i64, nanos: u32) -> Option<Self> {
+        match nanos {
+            0..=999_999_999 => Some(unsafe { Self::new_unchecked(secs, nanos) }),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    #[const_fn::const_fn("1.56")]
+    pub(crate) unsafe fn new_unchecked(secs: i64, nanos: u32) -> Self {
+        Self {
+            secs,
+            nanos: U30::new_unchecked(nanos),
+        }
+    }
+
+    /// Total number of whole seconds since 1970-01-01T00:00:00 TAI
+    #[must_use]
+    #[inline]
+    pub const fn as_secs(self) -> i64 {
+        self.secs
+    }
+
+    /// Fractional number of nanoseconds
+    #[must_use]
+    #[inline]
+    #[const_fn::const_fn("1.56")]
+    pub fn subsec_nanos(self) -> u32 {
+        self.nanos.get()
+    }
+
+    /// Total number of whole nanoseconds since 1970-01-01T00:00:00 TAI
+    #[must_use]
+    #[const_fn::const_fn("1.56")]
+    pub fn as_nanos(self) -> i128 {
+        (self.secs as i128 * 1_000_000_000) + (self.nanos.get() as i128)
+    }
+
+    /// Convert to UTC using the [installed](LeapSecondTable::install) leap second table
+    ///
+    /// A positive leap second, e.g. 2016-12-31T23:59:60 UTC, cannot be expressed as [`UtcTime`].
+    /// It is folded into the next second, i.e. 2017-01-01T00:00:00 UTC, with the same fractional seconds,
+    /// the same way [`UtcTime::parse_rfc3339()`] treats a second `60`.
+    /// Use [`TaiTime::is_leap_second()`] to detect this case.
+    ///
+    /// Returns `None` if the result would overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let leap = UtcTime::parse_rfc3339("2016-12-31T23:59:59.5Z").unwrap().to_tai().unwrap();
+    /// assert!(!leap.is_leap_second());
+    /// let leap = utcnow::TaiTime::new(leap.as_secs() + 1, leap.subsec_nanos()).unwrap();
+    /// assert!(leap.is_leap_second());
+    /// assert_eq!(
+    ///     leap.to_utc().unwrap().rfc3339().to_string(),
+    ///     "2017-01-01T00:00:00.5Z",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_utc(self) -> Option<UtcTime> {
+        LeapSecondTable::with_installed(|table| table.tai_to_utc(self))
+    }
+
+    /// `true` if this timestamp lies in a positive leap second, e.g. 2016-12-31T23:59:60 UTC
+    ///
+    /// Uses the [installed](LeapSecondTable::install) leap second table.
+    #[must_use]
+    pub fn is_leap_second(self) -> bool {
+        LeapSecondTable::with_installed(|table| table.is_leap_second(self))
+    }
+}
+
+impl fmt::Display for TaiTime {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{:09}", self.secs, self.nanos)
+    }
+}
+
+impl UtcTime {
+    /// Convert to TAI using the [installed](LeapSecondTable::install) leap second table
+    ///
+    /// Every [`UtcTime`] has exactly one TAI representation, because a POSIX timestamp cannot express a leap second.
+    /// Returns `None` if the result would overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::UtcTime;
+    /// let utc = UtcTime::parse_rfc3339("2017-01-01T00:00:00Z").unwrap();
+    /// let tai = utc.to_tai().unwrap();
+    /// assert_eq!(tai.as_secs() - utc.as_secs(), 37);
+    /// assert_eq!(tai.to_utc(), Some(utc));
+    /// ```
+    #[must_use]
+    pub fn to_tai(self) -> Option<TaiTime> {
+        LeapSecondTable::with_installed(|table| table.utc_to_tai(self))
+    }
+}
+
+impl RawClockReading {
+    /// Interpret the reading as [`TaiTime`]
+    ///
+    /// Returns `None` unless the reading was taken from [`ClockSource::Tai`](crate::ClockSource::Tai).
+    #[must_use]
+    pub fn to_tai_time(self) -> Option<TaiTime> {
+        match self.source() {
+            crate::ClockSource::Tai => {
+                Some(unsafe { TaiTime::new_unchecked(self.as_secs(), self.subsec_nanos()) })
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Offset of TAI to UTC in seconds at a given time, using the [installed](LeapSecondTable::install) table
+///
+/// Times before 1972-01-01, when the current definition of UTC was introduced, use the initial offset of 10 s.
+///
+/// # Example
+///
+/// ```
+/// # use utcnow::{UtcTime, tai_offset_at};
+/// assert_eq!(tai_offset_at(UtcTime::parse_rfc3339("2016-12-31T23:59:59Z").unwrap()), 36);
+/// assert_eq!(tai_offset_at(UtcTime::parse_rfc3339("2017-01-01T00:00:00Z").unwrap()), 37);
+/// ```
+#[must_use]
+pub fn tai_offset_at(time: UtcTime) -> i32 {
+    LeapSecondTable::with_installed(|table| table.tai_offset_at(time))
+}
+
+/// A change of the offset between TAI and UTC
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LeapSecond {
+    time: UtcTime,
+    tai_offset: i32,
+}
+
+impl LeapSecond {
+    /// A new offset `tai_offset` that is in effect from `time` on
+    #[must_use]
+    #[inline]
+    pub const fn new(time: UtcTime, tai_offset: i32) -> Self {
+        Self { time, tai_offset }
+    }
+
+    /// The first second in UTC the offset is in effect, e.g. 2017-01-01T00:00:00Z
+    #[must_use]
+    #[inline]
+    pub const fn time(self) -> UtcTime {
+        self.time
+    }
+
+    /// TAI minus UTC in seconds from [`time()`](LeapSecond::time) on
+    #[must_use]
+    #[inline]
+    pub const fn tai_offset(self) -> i32 {
+        self.tai_offset
+    }
+}
+
+/// A list of all leap seconds, used to convert between [`UtcTime`] and [`TaiTime`]
+///
+/// The library contains a [built-in table](LeapSecondTable::builtin) with all leap seconds up to 2017-01-01.
+/// With the feature `"std"` you can [load](LeapSecondTable::load_iers_file) an updated `leap-seconds.list` file
+/// published by the [IERS](https://hpiers.obspm.fr/iers/bul/bulc/ntp/leap-seconds.list),
+/// and [install](LeapSecondTable::install) it for the whole process.
+#[allow(missing_copy_implementations)] // only `Copy` without the feature "std"
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LeapSecondTable {
+    #[cfg(feature = "std")]
+    entries: Cow<'static, [LeapSecond]>,
+    #[cfg(not(feature = "std"))]
+    entries: &'static [LeapSecond],
+    expires: Option<UtcTime>,
+}
+
+const fn leap(secs: i64, tai_offset: i32) -> LeapSecond {
+    LeapSecond {
+        time: UtcTime {
+            secs,
+            nanos: U30::ZERO,
+        },
+        tai_offset,
+    }
+}
+
+/// IERS `leap-seconds.list`, updated 2026-01-06, expires 2026-12-28
+const BUILTIN_ENTRIES: [LeapSecond; 28] = [
+    leap(63_072_000, 10),    // 1972-01-01
+    leap(78_796_800, 11),    // 1972-07-01
+    leap(94_694_400, 12),    // 1973-01-01
+    leap(126_230_400, 13),   // 1974-01-01
+    leap(157_766_400, 14),   // 1975-01-01
+    leap(189_302_400, 15),   // 1976-01-01
+    leap(220_924_800, 16),   // 1977-01-01
+    leap(252_460_800, 17),   // 1978-01-01
+    leap(283_996_800, 18),   // 1979-01-01
+    leap(315_532_800, 19),   // 1980-01-01
+    leap(362_793_600, 20),   // 1981-07-01
+    leap(394_329_600, 21),   // 1982-07-01
+    leap(425_865_600, 22),   // 1983-07-01
+    leap(489_024_000, 23),   // 1985-07-01
+    leap(567_993_600, 24),   // 1988-01-01
+    leap(631_152_000, 25),   // 1990-01-01
+    leap(662_688_000, 26),   // 1991-01-01
+    leap(709_948_800, 27),   // 1992-07-01
+    leap(741_484_800, 28),   // 1993-07-01
+    leap(773_020_800, 29),   // 1994-07-01
+    leap(820_454_400, 30),   // 1996-01-01
+    leap(867_715_200, 31),   // 1997-07-01
+    leap(915_148_800, 32),   // 1999-01-01
+    leap(1_136_073_600, 33), // 2006-01-01
+    leap(1_230_768_000, 34), // 2009-01-01
+    leap(1_341_100_800, 35), // 2012-07-01
+    leap(1_435_708_800, 36), // 2015-07-01
+    leap(1_483_228_800, 37), // 2017-01-01
+];
+
+const BUILTIN: LeapSecondTable = LeapSecondTable {
+    #[cfg(feature = "std")]
+    entries: Cow::Borrowed(&BUILTIN_ENTRIES),
+    #[cfg(not(feature = "std"))]
+    entries: &BUILTIN_ENTRIES,
+    expires: Some(UtcTime {
+        secs: 1_798_416_000, // 2026-12-28
+        nanos: U30::ZERO,
+    }),
+};
+
+#[cfg(feature = "std")]
+static INSTALLED: LazyBox<RwLock<Option<Arc<LeapSecondTable>>>> = LazyBox::new();
+
+#[cfg(feature = "std")]
+fn installed() -> &'static RwLock<Option<Arc<LeapSecondTable>>> {
+    INSTALLED.get_or_init(|| RwLock::new(None))
+}
+
+impl LeapSecondTable {
+    /// The table that is built into this version of the library
+    ///
+    /// It contains all leap seconds up to 2017-01-01, and expires on 2026-12-28.
+    #[must_use]
+    #[inline]
+    pub const fn builtin() -> Self {
+        BUILTIN
+    }
+
+    /// All changes of the TAI offset, sorted by time
+    #[must_use]
+    #[inline]
+    pub fn entries(&self) -> &[LeapSecond] {
+        #[cfg(feature = "std")]
+        {
+            &self.entries
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            self.entries
+        }
+    }
+
+    /// Until when the table is known to be complete, if known
+    ///
+    /// After this date the IERS may have announced further leap seconds.
+    #[must_use]
+    #[inline]
+    pub const fn expires(&self) -> Option<UtcTime> {
+        self.expires
+    }
+
+    /// Offset of TAI to UTC in seconds at a given time
+    ///
+    /// Times before the first entry use the offset of the first entry.
+    #[must_use]
+    pub fn tai_offset_at(&self, time: UtcTime) -> i32 {
+        let mut offset = match self.entries.first() {
+            Some(first) => first.tai_offset,
+            None => 0,
+        };
+        for entry in self.entries.iter() {
+            if entry.time > time {
+                break;
+            }
+            offset = entry.tai_offset;
+        }
+        offset
+    }
+
+    /// Convert a [`UtcTime`] to [`TaiTime`] using this table
+    ///
+    /// See [`UtcTime::to_tai()`].
+    #[must_use]
+    pub fn utc_to_tai(&self, time: UtcTime) -> Option<TaiTime> {
+        let secs = time.secs.checked_add(self.tai_offset_at(time).into())?;
+        Some(unsafe { TaiTime::new_unchecked(secs, time.nanos.get()) })
+    }
+
+    /// Convert a [`TaiTime`] to [`UtcTime`] using this table
+    ///
+    /// See [`TaiTime::to_utc()`].
+    #[must_use]
+    pub fn tai_to_utc(&self, time: TaiTime) -> Option<UtcTime> {
+        let offset = self.offset_of_tai(time);
+        let secs = time.secs.checked_sub(offset.into())?;
+        Some(unsafe { UtcTime::new_unchecked(secs, time.nanos.get()) })
+    }
+
+    /// `true` if the timestamp lies in a positive leap second
+    ///
+    /// See [`TaiTime::is_leap_second()`].
+    #[must_use]
+    pub fn is_leap_second(&self, time: TaiTime) -> bool {
+        self.entries.windows(2).any(|pair| {
+            let (before, after) = (pair[0].tai_offset, pair[1].tai_offset);
+            let start = i128::from(pair[1].time.secs) + i128::from(before);
+            let end = i128::from(pair[1].time.secs) + i128::from(after);
+            (start..end).contains(&i128::from(time.secs))
+        })
+    }
+
+    /// The offset of the last entry that started before `time`
+    ///
+    /// During a positive leap second this is the offset before the leap second,
+    /// so the leap second is folded into the first second that uses the new offset.
+    fn offset_of_tai(&self, time: TaiTime) -> i32 {
+        for entry in self.entries.iter().rev() {
+            if i128::from(time.secs) >= i128::from(entry.time.secs) + i128::from(entry.tai_offset) {
+                return entry.tai_offset;
+            }
+        }
+        match self.entries.first() {
+            Some(first) => first.tai_offset,
+            None => 0,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn with_installed<R>(f: impl FnOnce(&LeapSecondTable) -> R) -> R {
+        let installed = installed()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        match installed {
+            Some(table) => f(&table),
+            None => f(&BUILTIN),
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn with_installed<R>(f: impl FnOnce(&LeapSecondTable) -> R) -> R {
+        f(&BUILTIN)
+    }
+}
+
+#[cfg(feature = "std")]
+impl LeapSecondTable {
+    /// Use this table for all conversions in this process, e.g. in [`UtcTime::to_tai()`]
+    ///
+    /// This function is only available with the feature `"std"`.
+    pub fn install(self) {
+        *installed().write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(self));
+    }
+
+    /// The table that is currently used for conversions
+    ///
+    /// This function is only available with the feature `"std"`.
+    #[must_use]
+    pub fn installed() -> Self {
+        Self::with_installed(Clone::clone)
+    }
+
+    /// Parse the content of an IERS `leap-seconds.list` file
+    ///
+    /// Data lines contain the time in seconds since 1900-01-01 (NTP epoch) and the new TAI offset.
+    /// The line starting with `#@` contains the expiration date of the file. Other comments are ignored.
+    /// The hash in the line starting with `#h` is not checked.
+    ///
+    /// This function is only available with the feature `"std"`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if a line is malformed, if the entries are not sorted,
+    /// or if the offset changes by more than one second at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use utcnow::{LeapSecondTable, UtcTime};
+    /// let table = LeapSecondTable::parse_iers("\
+    ///     #@\t3991593600\n\
+    ///     2272060800\t10\t# 1 Jan 1972\n\
+    ///     2287785600\t11\t# 1 Jul 1972\n\
+    /// ").unwrap();
+    /// assert_eq!(table.entries().len(), 2);
+    /// assert_eq!(table.expires(), UtcTime::parse_rfc3339("2026-06-28T00:00:00Z").ok());
+    /// ```
+    pub fn parse_iers(content: &str) -> Result<Self, ParseLeapSecondsError> {
+        let mut entries = Vec::<LeapSecond>::new();
+        let mut expires = None;
+        for (index, line) in content.lines().enumerate() {
+            let error = ParseLeapSecondsError { line: index + 1 };
+            let line = line.trim();
+            if let Some(expiration) = line.strip_prefix("#@") {
+                let secs = parse_ntp_secs(expiration.trim()).ok_or(error)?;
+                expires = Some(UtcTime::new(secs, 0).ok_or(error)?);
+                continue;
+            }
+
+            let data = match line.find('#') {
+                Some(idx) => &line[..idx],
+                None => line,
+            };
+            let mut fields = data.split_whitespace();
+            let (secs, offset) = match (fields.next(), fields.next(), fields.next()) {
+                (None, _, _) => continue,
+                (Some(secs), Some(offset), None) => (secs, offset),
+                _ => return Err(error),
+            };
+            let secs = parse_ntp_secs(secs).ok_or(error)?;
+            let tai_offset: i32 = offset.parse().map_err(|_| error)?;
+            if let Some(previous) = entries.last() {
+                if previous.time.secs >= secs || (previous.tai_offset - tai_offset).abs() != 1 {
+                    return Err(error);
+                }
+            }
+            entries.push(leap(secs, tai_offset));
+        }
+        if entries.is_empty() {
+            return Err(ParseLeapSecondsError {
+                line: content.lines().count(),
+            });
+        }
+        Ok(Self {
+            entries: Cow::Owned(entries),
+            expires,
+        })
+    }
+
+    /// Read and parse an IERS `leap-seconds.list` file, e.g. `/usr/share/zoneinfo/leap-seconds.list`
+    ///
+    /// See [`LeapSecondTable::parse_iers()`].
+    /// This function is only available with the feature `"std"`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file could not be read, or if the content is malformed.
+    /// In the latter case the error kind is [`InvalidData`](std::io::ErrorKind::InvalidData).
+    pub fn load_iers_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::parse_iers(&content)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+}
+
+#[cfg(feature = "std")]
+fn parse_ntp_secs(value: &str) -> Option<i64> {
+    let secs: i64 = value.parse().ok()?;
+    secs.checked_sub(crate::ntp::NTP_TO_UNIX)
+}
+
+/// Could not parse a leap second table
+///
+/// Returned by [`LeapSecondTable::parse_iers()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParseLeapSecondsError {
+    line: usize,
+}
+
+impl ParseLeapSecondsError {
+    /// The line number (starting at 1) where the error occurred
+    #[must_use]
+    #[inline]
+    pub const fn line(self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for ParseLeapSecondsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "malformed leap second table in line {}", self.line)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseLeapSecondsError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CivilDateTime, LeapSecondTable, TaiTime, UtcTime, tai_offset_at};
+
+    fn utc(s: &str) -> UtcTime {
+        UtcTime::parse_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn test_builtin() {
+        let table = LeapSecondTable::builtin();
+        for (idx, entry) in table.entries().iter().enumerate() {
+            let civil = entry.time().to_civil();
+            assert!(civil.month == 1 || civil.month == 7);
+            assert_eq!(
+                civil,
+                CivilDateTime::from_date(civil.year, civil.month, 1).unwrap(),
+            );
+            assert_eq!(entry.tai_offset(), 10 + idx as i32);
+        }
+        assert_eq!(
+            table.expires().unwrap().rfc3339().to_string(),
+            "2026-12-28T00:00:00Z",
+        );
+    }
+
+    #[test]
+    fn test_offset() {
+        assert_eq!(tai_offset_at(UtcTime::EPOCH), 10);
+        assert_eq!(tai_offset_at(utc("1972-06-30T23:59:59.999999999Z")), 10);
+        assert_eq!(tai_offset_at(utc("1972-07-01T00:00:00Z")), 11);
+        assert_eq!(tai_offset_at(utc("2016-12-31T23:59:59Z")), 36);
+        assert_eq!(tai_offset_at(utc("2017-01-01T00:00:00Z")), 37);
+        assert_eq!(tai_offset_at(UtcTime::MAX), 37);
+        assert_eq!(tai_offset_at(UtcTime::MIN), 10);
+    }
+
+    #[test]
+    fn test_leap_second() {
+        let before = utc("2016-12-31T23:59:59.25Z").to_tai().unwrap();
+        let after = utc("2017-01-01T00:00:00.25Z").to_tai().unwrap();
+        assert_eq!(after.as_nanos() - before.as_nanos(), 2_000_000_000);
+
+        let leap = TaiTime::new(before.as_secs() + 1, 250_000_000).unwrap();
+        assert!(!before.is_leap_second());
+        assert!(leap.is_leap_second());
+        assert!(!after.is_leap_second());
+
+        assert_eq!(before.to_utc(), Some(utc("2016-12-31T23:59:59.25Z")));
+        assert_eq!(leap.to_utc(), Some(utc("2017-01-01T00:00:00.25Z")));
+        assert_eq!(after.to_utc(), Some(utc("2017-01-01T00:00:00.25Z")));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let table = LeapSecondTable::builtin();
+        for entry in table.entries() {
+            for delta in -3..=3 {
+                let time = UtcTime::new(entry.time().as_secs() + delta, 123).unwrap();
+                let tai = table.utc_to_tai(time).unwrap();
+                assert_eq!(table.tai_to_utc(tai), Some(time));
+            }
+        }
+        assert_eq!(table.utc_to_tai(UtcTime::MAX), None);
+        assert_eq!(table.tai_to_utc(TaiTime::new(i64::MIN, 0).unwrap()), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_iers() {
+        // the offset must not change by more than one second at once
+        let err = LeapSecondTable::parse_iers(
+            "#\tUpdated through IERS Bulletin C70\n\
+             #$\t 3929093563\n\
+             #@\t3991593600\n\
+             \n\
+             2272060800\t10\t# 1 Jan 1972\n\
+             2287785600\t11\t# 1 Jul 1972\n\
+             3692217600\t37\t# 1 Jan 2017\n\
+             #h\tabc def\n",
+        )
+        .unwrap_err();
+        assert_eq!(err.line(), 7);
+
+        let content: String = LeapSecondTable::builtin()
+            .entries()
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{}\t{}\t# comment\n",
+                    entry.time().as_secs() + crate::ntp::NTP_TO_UNIX,
+                    entry.tai_offset(),
+                )
+            })
+            .collect();
+        let content = format!("#@\t4007404800\n{}#h\tabc def\n", content);
+        assert_eq!(
+            LeapSecondTable::parse_iers(&content),
+            Ok(LeapSecondTable::builtin()),
+        );
+
+        for &(content, line) in &[
+            ("", 0),
+            ("# only a comment", 1),
+            ("2272060800\n", 1),
+            ("2272060800\t10\t11\n", 1),
+            ("2272060800\tten\n", 1),
+            ("2272060800\t10\n2272060800\t11\n", 2),
+            ("2272060800\t10\n2287785600\t12\n", 2),
+            ("#@\tsoon\n", 1),
+        ] {
+            let err = LeapSecondTable::parse_iers(content).unwrap_err();
+            assert_eq!(err.line(), line, "{:?}", content);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_load_iers_file() {
+        let path =
+            std::env::temp_dir().join(format!("utcnow-leap-seconds-{}.list", std::process::id()));
+        std::fs::write(&path, "2272060800\t10\n2287785600\t11\n").unwrap();
+        let table = LeapSecondTable::load_iers_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(table.entries().len(), 2);
+        assert_eq!(table.expires(), None);
+        assert_eq!(table.tai_offset_at(utc("2017-01-01T00:00:00Z")), 11);
+    }
+}
@@ -1,4 +1,7 @@
-use super::{Result, U30, UtcTime, utcnow};
+use core::convert::TryFrom;
+use core::time::Duration;
+
+use super::{ConversionErrorKind, Result, U30, UtcDelta, UtcTime, utcnow};
 
 #[test]
 #[allow(unknown_lints)]
@@ -17,6 +20,14 @@ fn test_layout() {
 
     assert_eq!(mem::size_of::<UtcTime>(), mem::size_of::<Option<UtcTime>>());
     assert_eq!(mem::size_of::<UtcTime>(), mem::size_of::<Result<UtcTime>>());
+
+    assert_eq!(mem::size_of::<UtcTime>(), mem::size_of::<UtcDelta>());
+    assert_eq!(
+        mem::size_of::<UtcDelta>(),
+        mem::size_of::<Option<UtcDelta>>()
+    );
+
+    assert_eq!(U30::MAX.get(), 999_999_999);
 }
 
 #[test]
@@ -94,4 +105,118 @@ fn test_parse() {
     assert!("1661201091 ".parse::<UtcTime>().is_err());
     assert!("1661201091. 1".parse::<UtcTime>().is_err());
     assert!("1661201091 .1".parse::<UtcTime>().is_err());
+    assert!("1.+5".parse::<UtcTime>().is_err());
+    assert!("-.5".parse::<UtcTime>().is_err());
+    assert!("+.5".parse::<UtcTime>().is_err());
+    assert_eq!("-0.5".parse::<UtcTime>().ok(), UtcTime::new(0, 500_000_000));
+    assert_eq!("+0.5".parse::<UtcTime>().ok(), UtcTime::new(0, 500_000_000));
+}
+
+#[test]
+fn test_conversion_error_kind() {
+    let kind = |s: &str| s.parse::<UtcTime>().unwrap_err().kind();
+    assert_eq!(kind(""), ConversionErrorKind::Empty);
+    assert_eq!(kind("-."), ConversionErrorKind::Empty);
+    assert_eq!(kind("-.5"), ConversionErrorKind::InvalidCharacter {
+        index: 1
+    });
+    assert_eq!(kind(":D"), ConversionErrorKind::InvalidCharacter {
+        index: 0
+    });
+    assert_eq!(
+        kind("1661201091. 1"),
+        ConversionErrorKind::InvalidCharacter { index: 11 },
+    );
+    assert_eq!(kind("9223372036854775808"), ConversionErrorKind::Overflow);
+    assert_eq!(kind("-9223372036854775809"), ConversionErrorKind::Overflow);
+    assert_eq!(
+        kind("99999999999999999999.5"),
+        ConversionErrorKind::TooManyDigits,
+    );
+    assert_eq!("-9223372036854775808".parse::<UtcTime>(), Ok(UtcTime::MIN),);
+
+    assert_eq!(
+        UtcTime::new(-1, 0)
+            .unwrap()
+            .into_duration()
+            .unwrap_err()
+            .kind(),
+        ConversionErrorKind::Negative,
+    );
+    assert_eq!(
+        UtcTime::try_from(Duration::from_secs(u64::MAX))
+            .unwrap_err()
+            .kind(),
+        ConversionErrorKind::Overflow,
+    );
+    #[cfg(feature = "std")]
+    {
+        use std::time::SystemTime;
+
+        let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+        assert_eq!(
+            UtcTime::try_from(before_epoch).unwrap_err().kind(),
+            ConversionErrorKind::Negative,
+        );
+    }
+}
+
+#[test]
+fn test_from_units() {
+    const SECS: UtcTime = UtcTime::from_secs(-5);
+    assert_eq!((SECS.as_secs(), SECS.subsec_nanos()), (-5, 0));
+
+    let time = UtcTime::from_millis(-1_001).unwrap();
+    assert_eq!((time.as_secs(), time.subsec_nanos()), (-2, 999_000_000));
+    let time = UtcTime::from_micros(-1).unwrap();
+    assert_eq!((time.as_secs(), time.subsec_nanos()), (-1, 999_999_000));
+    let time = UtcTime::from_nanos(-1_000_000_000).unwrap();
+    assert_eq!((time.as_secs(), time.subsec_nanos()), (-1, 0));
+
+    assert_eq!(
+        UtcTime::from_nanos(UtcTime::MAX.as_nanos()),
+        Some(UtcTime::MAX)
+    );
+    assert_eq!(
+        UtcTime::from_nanos(UtcTime::MIN.as_nanos()),
+        Some(UtcTime::MIN)
+    );
+    assert_eq!(UtcTime::from_nanos(UtcTime::MAX.as_nanos() + 1), None);
+    assert_eq!(UtcTime::from_nanos(UtcTime::MIN.as_nanos() - 1), None);
+    assert_eq!(
+        UtcTime::from_millis(i128::from(i64::MIN) * 1_000),
+        Some(UtcTime::MIN)
+    );
+    assert_eq!(UtcTime::from_millis(i128::from(i64::MIN) * 1_000 - 1), None);
+    assert_eq!(UtcTime::from_micros(i128::MAX), None);
+    assert_eq!(UtcTime::from_micros(i128::MIN), None);
+}
+
+#[test]
+fn test_secs_f64() {
+    let time = UtcTime::from_secs_f64(-1.5).unwrap();
+    assert_eq!((time.as_secs(), time.subsec_nanos()), (-2, 500_000_000));
+    assert_eq!(time.as_secs_f64(), -1.5);
+    let time = UtcTime::from_secs_f64(-0.0).unwrap();
+    assert_eq!(time, UtcTime::EPOCH);
+    let time = UtcTime::from_secs_f64(1e-10).unwrap();
+    assert_eq!(time, UtcTime::EPOCH);
+    let time = UtcTime::from_secs_f64(5e-10).unwrap();
+    assert_eq!((time.as_secs(), time.subsec_nanos()), (0, 1));
+    let time = UtcTime::from_secs_f64(-1e-10).unwrap();
+    assert_eq!(time, UtcTime::EPOCH);
+    let time = UtcTime::from_secs_f64(1_659_545_693.895_531).unwrap();
+    assert_eq!(time.as_secs(), 1_659_545_693);
+    // the closest f64 is 1_659_545_693.895_530_939_1…
+    assert_eq!(time.subsec_nanos(), 895_530_939);
+
+    assert_eq!(
+        UtcTime::from_secs_f64(-9_223_372_036_854_775_808.0),
+        Some(UtcTime::MIN)
+    );
+    assert_eq!(UtcTime::from_secs_f64(9_223_372_036_854_775_808.0), None);
+    assert_eq!(UtcTime::from_secs_f64(-9_223_372_036_854_777_856.0), None);
+    assert_eq!(UtcTime::from_secs_f64(f64::NAN), None);
+    assert_eq!(UtcTime::from_secs_f64(f64::INFINITY), None);
+    assert_eq!(UtcTime::from_secs_f64(f64::NEG_INFINITY), None);
 }
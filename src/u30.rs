@@ -6,6 +6,16 @@ use core::{cmp, fmt, hash, mem};
 pub(crate) struct U30(Buf);
 
 impl U30 {
+    pub(crate) const MAX: U30 = {
+        // 999_999_999 == 0x3B_9A_C9_FF
+        #[cfg(target_endian = "little")]
+        let (a, b, c, d) = (0xFF, 0xC9, 0x9A, MaxByte::V59);
+        #[cfg(target_endian = "big")]
+        let (a, b, c, d) = (MaxByte::V59, 0x9A, 0xC9, 0xFF);
+
+        let align = [];
+        U30(Buf { align, a, b, c, d })
+    };
     pub(crate) const ZERO: U30 = {
         #[cfg(target_endian = "little")]
         let a = 0;
@@ -29,6 +39,7 @@ impl U30 {
     #[allow(unconditional_panic)]
     #[allow(clippy::out_of_bounds_indexing)]
     #[inline]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
     #[const_fn::const_fn("1.56")]
     pub(crate) const unsafe fn new_unchecked(value: u32) -> Self {
         if cfg!(debug_assertions) && value > 1_000_000_000 {
@@ -40,6 +51,7 @@ impl U30 {
     }
 
     #[inline]
+    #[allow(clippy::incompatible_msrv)] // false positive for `#[const_fn]`
     #[const_fn::const_fn("1.56")]
     pub(crate) const fn get(self) -> u32 {
         unsafe { mem::transmute(self) }
@@ -0,0 +1,38 @@
+//! The installed leap second table affects the whole process, so it is tested in its own binary.
+
+use utcnow::{LeapSecondTable, UtcTime, tai_offset_at};
+
+#[test]
+fn test_install() {
+    let before = UtcTime::parse_rfc3339("2026-12-31T23:59:59Z").unwrap();
+    let after = UtcTime::parse_rfc3339("2027-01-01T00:00:00Z").unwrap();
+    assert_eq!(LeapSecondTable::installed(), LeapSecondTable::builtin());
+    assert_eq!(tai_offset_at(after), 37);
+
+    // a hypothetical leap second at the end of 2026
+    let content: String = LeapSecondTable::builtin()
+        .entries()
+        .iter()
+        .map(|entry| {
+            format!(
+                "{}\t{}\n",
+                entry.time().as_secs() + 2_208_988_800,
+                entry.tai_offset()
+            )
+        })
+        .collect();
+    let content = format!("#@\t4023302400\n{}4007750400\t38\t# 1 Jan 2027\n", content);
+    let table = LeapSecondTable::parse_iers(&content).unwrap();
+    table.clone().install();
+
+    assert_eq!(LeapSecondTable::installed(), table);
+    assert_eq!(tai_offset_at(before), 37);
+    assert_eq!(tai_offset_at(after), 38);
+    assert_eq!(
+        after.to_tai().unwrap().as_secs() - before.to_tai().unwrap().as_secs(),
+        2,
+    );
+
+    LeapSecondTable::builtin().install();
+    assert_eq!(tai_offset_at(after), 37);
+}
@@ -0,0 +1,37 @@
+//! The global override affects all threads of the process, so it is tested in its own binary.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use utcnow::mock::{set_global, set_thread_local};
+use utcnow::{FixedClock, ManualClock, UtcTime, utcnow};
+
+#[test]
+fn test_global() {
+    let real = utcnow().unwrap();
+
+    let clock = Arc::new(ManualClock::new(UtcTime::EPOCH));
+    let outer = set_global(Arc::clone(&clock));
+    assert_eq!(utcnow().unwrap(), UtcTime::EPOCH);
+    clock.advance(Duration::from_secs(60));
+    let handle = std::thread::spawn(|| utcnow().unwrap());
+    assert_eq!(handle.join().unwrap().as_secs(), 60);
+
+    let inner = set_global(FixedClock(UtcTime::MAX));
+    assert_eq!(utcnow().unwrap(), UtcTime::MAX);
+
+    // a thread-local override takes precedence
+    let local = set_thread_local(FixedClock(UtcTime::MIN));
+    assert_eq!(utcnow().unwrap(), UtcTime::MIN);
+    drop(local);
+    assert_eq!(utcnow().unwrap(), UtcTime::MAX);
+
+    drop(inner);
+    assert_eq!(utcnow().unwrap().as_secs(), 60);
+    assert_eq!(format!("{:?}", outer), "GlobalGuard");
+
+    drop(outer);
+    assert!(utcnow().unwrap() >= real);
+    let handle = std::thread::spawn(|| utcnow().unwrap());
+    assert!(handle.join().unwrap() >= real);
+}